@@ -0,0 +1,43 @@
+use std::fmt;
+
+/// The ways a linear-algebra operation can fail
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LinalgError
+{
+    /// The matrix is singular
+    Singular,
+    /// The matrix is so ill-conditioned that the result would be numerically meaningless
+    IllConditioned,
+    /// The matrix does not have full column rank
+    RankDeficient,
+    /// The matrix does not have the shape the operation requires, such as a wide matrix where a tall one is needed
+    DimensionMismatch,
+    /// The matrix is not Hermitian positive-definite
+    NotPositiveDefinite,
+    /// An iterative algorithm did not converge within its iteration limit
+    NoConvergence,
+    /// The matrix has an eigenvalue on the closed negative real axis, where the principal branch is undefined
+    EigenvalueOnNegativeRealAxis,
+    /// The matrix has eigenvalues too close to each other for the algorithm to separate them
+    RepeatedEigenvalues
+}
+
+impl fmt::Display for LinalgError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            LinalgError::Singular => write!(f, "matrix is singular"),
+            LinalgError::IllConditioned => write!(f, "matrix is ill-conditioned"),
+            LinalgError::RankDeficient => write!(f, "matrix is rank-deficient"),
+            LinalgError::DimensionMismatch => write!(f, "matrix has unsupported dimensions"),
+            LinalgError::NotPositiveDefinite => write!(f, "matrix is not positive-definite"),
+            LinalgError::NoConvergence => write!(f, "iteration did not converge"),
+            LinalgError::EigenvalueOnNegativeRealAxis => write!(f, "matrix has an eigenvalue on the negative real axis"),
+            LinalgError::RepeatedEigenvalues => write!(f, "matrix has repeated eigenvalues")
+        }
+    }
+}
+
+impl std::error::Error for LinalgError {}
\ No newline at end of file
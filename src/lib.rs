@@ -1,17 +1,20 @@
 #![feature(generic_const_exprs)]
 #![feature(portable_simd)]
-#![recursion_limit = "256"]
 #[allow(incomplete_features)]
 #[allow(const_evaluatable_unchecked)]
 
 pub mod matrix;
 pub mod vector;
+pub mod error;
 pub use crate::matrix::*;
 pub use crate::vector::*;
+pub use crate::error::*;
 
 #[cfg(test)]
 mod tests {
-    use crate::{Det, MAdd, Trace, Matrix, MInv, Cross, MMul, Eig, QRHouseholder};
+    use num_complex::Complex;
+
+    use crate::{Herm, Transpose, Det, MAdd, Trace, Matrix, MInv, Cross, MMul, Eig, QRHouseholder, LU, Solve, Adj, DetBareiss, DetLU, AdjLU, Cholesky, CholeskySolve, EigVectors, SymmetricEig, Svd, PInv, LeastSquares, TriangularSolve, Hessenberg, Schur, QRHouseholderPivoted, QRGivens, QRUpdate, Expm, Funm, Sqrtm, Logm, MPow, CharPoly, PolyEval, MNorm, Cond, MInvChecked, LinalgError, Mat, Row, Column, Dot, MSub, MAddAssign, MSubAssign, MScaleMut, TransposeInPlace};
 
     #[test]
     fn test_det()
@@ -91,10 +94,10 @@ mod tests {
             [-2.0, 1.0, -3.0],
             [1.0, -1.5, 1.0]
         ];
-        let eig: Vec<String> = a.eig().iter().map(|yn| yn.to_string()).collect();
+        let eig: Vec<String> = a.eig().unwrap().iter().map(|yn| yn.to_string()).collect();
         println!("detA = {}", a.det());
         println!("lambda = [\n{}\n]", eig.join("\n"));
-        println!("mul lambda = {}", a.eig().iter().map(|l| *l).reduce(|a, b| a*b).unwrap());
+        println!("mul lambda = {}", a.eig().unwrap().iter().map(|l| *l).reduce(|a, b| a*b).unwrap());
     }
 
     #[test]
@@ -112,4 +115,821 @@ mod tests {
         let qr = q.mul(r);
         println!("qr = [\n{}\n]", qr.map(|ar| ar.map(|arc| arc.to_string()).join(", ")).join("\n"));
     }
-}
+
+    #[test]
+    fn lu()
+    {
+        let a: [[f64; 3]; 3] = [
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 10.0]
+        ];
+        let (p, l, u) = a.lu();
+        println!("l = [\n{}\n]", l.map(|ar| ar.map(|arc| arc.to_string()).join(", ")).join("\n"));
+        println!("u = [\n{}\n]", u.map(|ar| ar.map(|arc| arc.to_string()).join(", ")).join("\n"));
+        let pa = p.mul(a);
+        let lu = l.mul(u);
+        assert!((0..3).all(|r| (0..3).all(|c| (pa[r][c] - lu[r][c]).abs() < 1e-12)));
+    }
+
+    #[test]
+    fn solve()
+    {
+        let a: [[f64; 3]; 3] = [
+            [2.0, 1.0, -1.0],
+            [-3.0, -1.0, 2.0],
+            [-2.0, 1.0, 2.0]
+        ];
+        let b = [8.0, -11.0, -3.0];
+        let x = a.solve(b).unwrap();
+        println!("x = [{}]", x.map(|xn| xn.to_string()).join(", "));
+        assert!(x.iter().zip([2.0, 3.0, -1.0]).all(|(xn, en)| (xn - en).abs() < 1e-12));
+
+        let b: [[f64; 2]; 3] = [
+            [8.0, 1.0],
+            [-11.0, 0.0],
+            [-3.0, 0.0]
+        ];
+        let x = a.solve(b).unwrap();
+        let ax = a.mul(x);
+        assert!((0..3).all(|r| (0..2).all(|c| (ax[r][c] - b[r][c]).abs() < 1e-12)));
+
+        let a: [[f64; 2]; 2] = [
+            [1.0, 2.0],
+            [2.0, 4.0]
+        ];
+        assert_eq!(a.solve([1.0, 2.0]), None);
+    }
+
+    #[test]
+    fn adj()
+    {
+        let a: [[i64; 4]; 4] = [
+            [2, -1, 0, 3],
+            [1, 4, -2, 0],
+            [0, 5, 1, -1],
+            [3, 0, 2, 1]
+        ];
+        let d = a.det();
+        let aa = a.mul(a.adj());
+        assert!((0..4).all(|r| (0..4).all(|c| aa[r][c] == if r == c {d} else {0})));
+
+        let a: [[i64; 3]; 3] = [
+            [1, 2, 3],
+            [4, 5, 6],
+            [7, 8, 9]
+        ];
+        assert_eq!(a.det(), 0);
+        assert_eq!(a.adj(), [
+            [-3, 6, -3],
+            [6, -12, 6],
+            [-3, 6, -3]
+        ]);
+
+        let a: [[i64; 20]; 20] = crate::matrix_init(|r, c| if r == c {2} else if c == r + 1 {1} else {0});
+        assert_eq!(a.det(), 1 << 20);
+
+        // The LU-decomposition gives the same results for floating-point matrices
+        let a: [[f64; 4]; 4] = [
+            [2.0, -1.0, 0.0, 3.0],
+            [1.0, 4.0, -2.0, 0.0],
+            [0.0, 5.0, 1.0, -1.0],
+            [3.0, 0.0, 2.0, 1.0]
+        ];
+        let d = a.det_lu();
+        assert!((d - [[2, -1, 0, 3], [1, 4, -2, 0], [0, 5, 1, -1], [3, 0, 2, 1]].det() as f64).abs() < 1e-12);
+        let aa = a.mul(a.adj_lu());
+        assert!((0..4).all(|r| (0..4).all(|c| (aa[r][c] - if r == c {d} else {0.0}).abs() < 1e-12)));
+
+        let a: [[f64; 3]; 3] = [
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 9.0]
+        ];
+        let expected = [
+            [-3.0, 6.0, -3.0],
+            [6.0, -12.0, 6.0],
+            [-3.0, 6.0, -3.0]
+        ];
+        let aa = a.adj_lu();
+        assert!((0..3).all(|r| (0..3).all(|c| (aa[r][c] - expected[r][c]).abs() < 1e-12)));
+        assert_eq!([[1.0, 2.0], [2.0, 4.0]].adj(), [[4.0, -2.0], [-2.0, 1.0]]);
+
+        let a: [[f64; 20]; 20] = crate::matrix_init(|r, c| if r == c {2.0} else if c == r + 1 {1.0} else {0.0});
+        assert_eq!(a.det_lu(), (1 << 20) as f64);
+    }
+
+    #[test]
+    fn det_bareiss()
+    {
+        let a: [[i64; 4]; 4] = [
+            [0, 3, -1, 2],
+            [4, 0, 2, -3],
+            [1, -2, 5, 0],
+            [-3, 1, 0, 6]
+        ];
+        println!("det = {}", a.det_bareiss());
+        assert_eq!(a.det_bareiss(), a.det());
+
+        let a: [[i64; 3]; 3] = [
+            [1, 2, 3],
+            [4, 5, 6],
+            [7, 8, 9]
+        ];
+        assert_eq!(a.det_bareiss(), 0);
+    }
+
+    #[test]
+    fn cholesky()
+    {
+        let a: [[f64; 3]; 3] = [
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0]
+        ];
+        let l = a.cholesky().unwrap();
+        println!("l = [\n{}\n]", l.map(|ar| ar.map(|arc| arc.to_string()).join(", ")).join("\n"));
+        assert_eq!(l, [
+            [2.0, 0.0, 0.0],
+            [6.0, 1.0, 0.0],
+            [-8.0, 5.0, 3.0]
+        ]);
+
+        let b = [1.0, 2.0, 3.0];
+        let x = a.cholesky_solve(b).unwrap();
+        let y = a.solve(b).unwrap();
+        assert!(x.iter().zip(y).all(|(xn, yn)| (xn - yn).abs() < 1e-9));
+
+        let a: [[Complex<f64>; 2]; 2] = [
+            [Complex::new(2.0, 0.0), Complex::new(0.0, -1.0)],
+            [Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)]
+        ];
+        let l = a.cholesky().unwrap();
+        let llh = l.mul(l.herm());
+        assert!((0..2).all(|r| (0..2).all(|c| (llh[r][c] - a[r][c]).norm() < 1e-12)));
+
+        let a: [[f64; 2]; 2] = [
+            [1.0, 2.0],
+            [2.0, 1.0]
+        ];
+        assert_eq!(a.cholesky(), None);
+    }
+
+    #[test]
+    fn eig_vectors()
+    {
+        let a: [[f64; 3]; 3] = [
+            [1.0, -2.5, 2.0],
+            [-2.0, 1.0, -3.0],
+            [1.0, -1.5, 1.0]
+        ];
+        let (lambda, v) = a.eig_vectors().unwrap();
+        println!("lambda = [\n{}\n]", lambda.map(|l| l.to_string()).join("\n"));
+        let a = a.map(|ar| ar.map(Complex::from));
+        let av = a.mul(v);
+        assert!((0..3).all(|r| (0..3).all(|c| (av[r][c] - v[r][c]*lambda[c]).norm() < 1e-9)));
+
+        // Rotation with a complex-conjugate pair of eigenvalues
+        let a: [[f64; 2]; 2] = [
+            [0.0, -1.0],
+            [1.0, 0.0]
+        ];
+        let (lambda, v) = a.eig_vectors().unwrap();
+        println!("lambda = [\n{}\n]", lambda.map(|l| l.to_string()).join("\n"));
+        let a = a.map(|ar| ar.map(Complex::from));
+        let av = a.mul(v);
+        assert!((0..2).all(|r| (0..2).all(|c| (av[r][c] - v[r][c]*lambda[c]).norm() < 1e-9)));
+    }
+
+    #[test]
+    fn eig_converges()
+    {
+        // Real matrix with a complex-conjugate pair of eigenvalues
+        let a: [[f64; 3]; 3] = [
+            [0.0, -1.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [0.0, 0.0, 2.0]
+        ];
+        let mut lambda = a.eig().unwrap();
+        lambda.sort_by(|a, b| a.re.partial_cmp(&b.re).unwrap().then(a.im.partial_cmp(&b.im).unwrap()));
+        println!("lambda = [\n{}\n]", lambda.map(|l| l.to_string()).join("\n"));
+        let expected = [Complex::new(0.0, -1.0), Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)];
+        assert!(lambda.iter().zip(expected).all(|(l, e)| (l - e).norm() < 1e-12));
+
+        // Companion matrix of (λ - 1)(λ - 2)(λ - 3)(λ - 4)
+        let a: [[f64; 4]; 4] = [
+            [0.0, 0.0, 0.0, -24.0],
+            [1.0, 0.0, 0.0, 50.0],
+            [0.0, 1.0, 0.0, -35.0],
+            [0.0, 0.0, 1.0, 10.0]
+        ];
+        let lambda = a.eig().unwrap();
+        assert!((1..=4).all(|k| lambda.iter().any(|l| (l - k as f64).norm() < 1e-9)));
+
+        let lambda = a.map(|ar| ar.map(Complex::from)).eig().unwrap();
+        assert!((1..=4).all(|k| lambda.iter().any(|l| (l - k as f64).norm() < 1e-9)));
+
+        assert_eq!(a.eig_with(f64::EPSILON, 1), None);
+    }
+
+    #[test]
+    fn symmetric_eig()
+    {
+        let a: [[f64; 3]; 3] = [
+            [2.0, -1.0, 0.0],
+            [-1.0, 2.0, -1.0],
+            [0.0, -1.0, 2.0]
+        ];
+        let (lambda, v) = a.symmetric_eig().unwrap();
+        println!("lambda = [{}]", lambda.map(|l| l.to_string()).join(", "));
+        let sqrt2 = 2f64.sqrt();
+        assert!(lambda.iter().zip([2.0 - sqrt2, 2.0, 2.0 + sqrt2]).all(|(l, e)| (l - e).abs() < 1e-12));
+        let av = a.mul(v);
+        assert!((0..3).all(|r| (0..3).all(|c| (av[r][c] - v[r][c]*lambda[c]).abs() < 1e-12)));
+
+        let a: [[Complex<f64>; 2]; 2] = [
+            [Complex::new(2.0, 0.0), Complex::new(0.0, -1.0)],
+            [Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)]
+        ];
+        let (lambda, v) = a.symmetric_eig().unwrap();
+        assert!(lambda.iter().zip([1.0, 3.0]).all(|(l, e)| (l - e).abs() < 1e-12));
+        let vhv = v.herm().mul(v);
+        assert!((0..2).all(|r| (0..2).all(|c| (vhv[r][c] - if r == c {1.0} else {0.0}).norm() < 1e-12)));
+        let av = a.mul(v);
+        assert!((0..2).all(|r| (0..2).all(|c| (av[r][c] - v[r][c]*lambda[c]).norm() < 1e-12)));
+    }
+
+    #[test]
+    fn svd()
+    {
+        let a: [[f64; 2]; 3] = [
+            [1.0, -0.8],
+            [-3.0, -0.5],
+            [4.0, 5.0]
+        ];
+        let (u, s, vh) = a.svd().unwrap();
+        println!("s = [\n{}\n]", s.map(|ar| ar.map(|arc| arc.to_string()).join(", ")).join("\n"));
+        let usvh = u.mul(s).mul(vh);
+        assert!((0..3).all(|r| (0..2).all(|c| (usvh[r][c] - a[r][c]).abs() < 1e-12)));
+        let uhu = u.herm().mul(u);
+        assert!((0..3).all(|r| (0..3).all(|c| (uhu[r][c] - if r == c {1.0} else {0.0}).abs() < 1e-12)));
+
+        // Wide and rank-deficient
+        let a: [[Complex<f64>; 3]; 2] = [
+            [Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(0.0, -1.0)],
+            [Complex::new(2.0, 2.0), Complex::new(4.0, 0.0), Complex::new(0.0, -2.0)]
+        ];
+        let (u, s, vh) = a.svd().unwrap();
+        assert!(s[1][1].norm() < 1e-12);
+        let usvh = u.mul(s).mul(vh);
+        assert!((0..2).all(|r| (0..3).all(|c| (usvh[r][c] - a[r][c]).norm() < 1e-12)));
+        let vvh = vh.herm().mul(vh);
+        assert!((0..3).all(|r| (0..3).all(|c| (vvh[r][c] - if r == c {1.0} else {0.0}).norm() < 1e-12)));
+    }
+
+    #[test]
+    fn pinv()
+    {
+        // Overdetermined with full column rank: A⁺ = (AᵀA)⁻¹Aᵀ
+        let a: [[f64; 2]; 3] = [
+            [1.0, 1.0],
+            [1.0, 2.0],
+            [1.0, 3.0]
+        ];
+        let ap = a.pinv().unwrap();
+        println!("ap = [\n{}\n]", ap.map(|ar| ar.map(|arc| arc.to_string()).join(", ")).join("\n"));
+        let ata = a.transpose().mul(a);
+        let expected = ata.solve(a.transpose()).unwrap();
+        assert!((0..2).all(|r| (0..3).all(|c| (ap[r][c] - expected[r][c]).abs() < 1e-12)));
+
+        // Rank-deficient: AA⁺A = A
+        let a: [[f64; 3]; 2] = [
+            [1.0, 2.0, 3.0],
+            [2.0, 4.0, 6.0]
+        ];
+        let ap = a.pinv().unwrap();
+        let aapa = a.mul(ap).mul(a);
+        assert!((0..2).all(|r| (0..3).all(|c| (aapa[r][c] - a[r][c]).abs() < 1e-12)));
+    }
+
+    #[test]
+    fn least_squares()
+    {
+        // Fit a line through points scattered around y = 1 + 2t
+        let t = [0.0, 1.0, 2.0, 3.0];
+        let y = [1.1, 2.9, 5.1, 6.9];
+        let a: [[f64; 2]; 4] = t.map(|tn| [1.0, tn]);
+        let (x, residual) = a.least_squares(y).unwrap();
+        println!("x = [{}], residual = {residual}", x.map(|xn| xn.to_string()).join(", "));
+        assert!((x[0] - 1.06).abs() < 1e-12);
+        assert!((x[1] - 1.96).abs() < 1e-12);
+        let r = (0..4).map(|i| (x[0] + x[1]*t[i] - y[i]).powi(2)).sum::<f64>().sqrt();
+        assert!((residual - r).abs() < 1e-12);
+
+        let a: [[Complex<f64>; 2]; 3] = [
+            [Complex::new(1.0, 1.0), Complex::new(0.0, 2.0)],
+            [Complex::new(2.0, 0.0), Complex::new(1.0, -1.0)],
+            [Complex::new(0.0, -1.0), Complex::new(3.0, 0.0)]
+        ];
+        let (q, r) = a.qr_householder();
+        let qr = q.mul(r);
+        assert!((0..3).all(|r| (0..2).all(|c| (qr[r][c] - a[r][c]).norm() < 1e-12)));
+        let b = [Complex::new(1.0, 0.0), Complex::new(0.0, 1.0), Complex::new(2.0, -1.0)];
+        let (x, _) = a.least_squares(b).unwrap();
+        let expected = a.pinv().unwrap().mul(b.map(|bn| [bn]));
+        assert!((0..2).all(|i| (x[i] - expected[i][0]).norm() < 1e-12));
+
+        let a: [[f64; 3]; 2] = [
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0]
+        ];
+        assert_eq!(a.least_squares([1.0, 2.0]), None);
+
+        // Collinear columns only cancel up to rounding
+        let a = [
+            [1.0, 2.0],
+            [2.0, 4.0],
+            [3.0, 6.0]
+        ];
+        assert_eq!(a.least_squares([1.0, 2.0, 3.0]), None);
+    }
+
+    #[test]
+    fn triangular_solve()
+    {
+        let u = [
+            [2.0, 1.0, -1.0],
+            [0.0, 3.0, 2.0],
+            [0.0, 0.0, 4.0]
+        ];
+        let b = [1.0, 2.0, 3.0];
+        let x = u.solve_upper_triangular(b).unwrap();
+        println!("x = [{}]", x.map(|xn| xn.to_string()).join(", "));
+        let ux: [[f64; 1]; 3] = u.mul(x.map(|xn| [xn]));
+        assert!((0..3).all(|i| (ux[i][0] - b[i]).abs() < 1e-12));
+
+        let x = u.solve_upper_triangular_transpose(b).unwrap();
+        let utx: [[f64; 1]; 3] = u.transpose().mul(x.map(|xn| [xn]));
+        assert!((0..3).all(|i| (utx[i][0] - b[i]).abs() < 1e-12));
+
+        let l = u.transpose();
+        let b = [[1.0, 0.0], [0.0, 1.0], [2.0, 2.0]];
+        let x = l.solve_lower_triangular(b).unwrap();
+        let lx: [[f64; 2]; 3] = l.mul(x);
+        assert!((0..3).all(|r| (0..2).all(|c| (lx[r][c] - b[r][c]).abs() < 1e-12)));
+        assert_eq!(l.solve_unit_lower_triangular([1.0, 0.0, 0.0]), [1.0, -1.0, 3.0]);
+        assert_eq!([[1.0, 2.0], [0.0, 0.0]].solve_upper_triangular([1.0, 1.0]), None);
+
+        let l = [
+            [Complex::new(2.0, 1.0), Complex::new(0.0, 0.0)],
+            [Complex::new(1.0, -1.0), Complex::new(0.0, 3.0)]
+        ];
+        let b = [Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let x = l.solve_lower_triangular_herm(b).unwrap();
+        let lhx: [[Complex<f64>; 1]; 2] = l.herm().mul(x.map(|xn| [xn]));
+        assert!((0..2).all(|i| (lhx[i][0] - b[i]).norm() < 1e-12));
+    }
+
+    #[test]
+    fn schur()
+    {
+        let a: [[f64; 4]; 4] = [
+            [4.0, 1.0, -2.0, 2.0],
+            [1.0, 2.0, 0.0, 1.0],
+            [-2.0, 0.0, 3.0, -2.0],
+            [2.0, 1.0, -2.0, -1.0]
+        ];
+        let (q, h) = a.hessenberg();
+        let qhqt: [[f64; 4]; 4] = q.mul(h).mul(q.transpose());
+        assert!((0..4).all(|r| (0..4).all(|c| (qhqt[r][c] - a[r][c]).abs() < 1e-12)));
+        assert!((2..4).all(|r| (0..r - 1).all(|c| h[r][c] == 0.0)));
+
+        // Rotation with complex-conjugate eigenvalues stays as a 2×2 block in the real Schur form
+        let a: [[f64; 3]; 3] = [
+            [1.0, -2.0, 0.0],
+            [2.0, 1.0, 3.0],
+            [0.0, 0.0, 5.0]
+        ];
+        let (q, t) = a.schur().unwrap();
+        println!("t = {t:?}");
+        let qtqt: [[f64; 3]; 3] = q.mul(t).mul(q.transpose());
+        assert!((0..3).all(|r| (0..3).all(|c| (qtqt[r][c] - a[r][c]).abs() < 1e-12)));
+        assert_eq!(t[2][0], 0.0);
+        assert!(t[1][0] == 0.0 || t[2][1] == 0.0);
+
+        let a: [[Complex<f64>; 3]; 3] = a.map(|ar| ar.map(Complex::from));
+        let (q, t) = a.schur().unwrap();
+        let qtqh: [[Complex<f64>; 3]; 3] = q.mul(t).mul(q.herm());
+        assert!((0..3).all(|r| (0..3).all(|c| (qtqh[r][c] - a[r][c]).norm() < 1e-12)));
+        assert!((0..3).all(|r| (0..r).all(|c| t[r][c] == Complex::from(0.0))));
+    }
+
+    #[test]
+    fn qr_pivoted()
+    {
+        // Third column is the sum of the first two
+        let a: [[f64; 3]; 4] = [
+            [1.0, 2.0, 3.0],
+            [0.0, 1.0, 1.0],
+            [1.0, 0.0, 1.0],
+            [2.0, 1.0, 3.0]
+        ];
+        let (q, r, p) = a.qr_householder_pivoted();
+        println!("r = {r:?}");
+        let ap: [[f64; 3]; 4] = a.mul(p);
+        let qr: [[f64; 3]; 4] = q.mul(r);
+        assert!((0..4).all(|i| (0..3).all(|j| (qr[i][j] - ap[i][j]).abs() < 1e-12)));
+        assert!(r[0][0].abs() >= r[1][1].abs() && r[1][1].abs() >= r[2][2].abs());
+        assert_eq!(a.rank(), 2);
+
+        // Wide matrices
+        let a: [[f64; 4]; 2] = [
+            [1.0, 2.0, 0.0, -1.0],
+            [3.0, 1.0, 2.0, 0.0]
+        ];
+        let (q, r, p) = a.qr_householder_pivoted();
+        let ap: [[f64; 4]; 2] = a.mul(p);
+        let qr: [[f64; 4]; 2] = q.mul(r);
+        assert!((0..2).all(|i| (0..4).all(|j| (qr[i][j] - ap[i][j]).abs() < 1e-12)));
+        assert_eq!(a.rank(), 2);
+    }
+
+    #[test]
+    fn qr_update()
+    {
+        let a: [[f64; 2]; 3] = [
+            [1.0, 2.0],
+            [3.0, 4.0],
+            [5.0, 7.0]
+        ];
+        let (q, r) = a.qr_givens();
+        let qr: [[f64; 2]; 3] = q.mul(r);
+        assert!((0..3).all(|i| (0..2).all(|j| (qr[i][j] - a[i][j]).abs() < 1e-12)));
+        assert_eq!(r[1][0], 0.0);
+        assert_eq!(r[2][1], 0.0);
+
+        let (q, r) = (q, r).add_row(1, [-1.0, 0.5]);
+        let qr: [[f64; 2]; 4] = q.mul(r);
+        println!("qr = {qr:?}");
+        let expected = [[1.0, 2.0], [-1.0, 0.5], [3.0, 4.0], [5.0, 7.0]];
+        assert!((0..4).all(|i| (0..2).all(|j| (qr[i][j] - expected[i][j]).abs() < 1e-12)));
+        assert!((1..4).all(|i| (0..i.min(2)).all(|j| r[i][j] == 0.0)));
+
+        let (q, r) = (q, r).remove_row(2);
+        let qr: [[f64; 2]; 3] = q.mul(r);
+        let expected = [[1.0, 2.0], [-1.0, 0.5], [5.0, 7.0]];
+        assert!((0..3).all(|i| (0..2).all(|j| (qr[i][j] - expected[i][j]).abs() < 1e-12)));
+        assert!((1..3).all(|i| (0..i.min(2)).all(|j| r[i][j].abs() < 1e-12)));
+
+        let a: [[Complex<f64>; 2]; 2] = [
+            [Complex::new(1.0, 1.0), Complex::new(2.0, 0.0)],
+            [Complex::new(0.0, -1.0), Complex::new(3.0, 1.0)]
+        ];
+        let u = [Complex::new(0.5, 0.0), Complex::new(1.0, -2.0)];
+        let v = [Complex::new(0.0, 1.0), Complex::new(-1.0, 0.0)];
+        let (q, r) = a.qr_givens().rank1_update(u, v);
+        let qr: [[Complex<f64>; 2]; 2] = q.mul(r);
+        assert!((0..2).all(|i| (0..2).all(|j| (qr[i][j] - (a[i][j] + u[i]*v[j].conj())).norm() < 1e-12)));
+        assert!(r[1][0].norm() < 1e-12);
+    }
+
+    #[test]
+    fn expm()
+    {
+        // Generator of rotations
+        let theta = 2.5;
+        let a: [[f64; 2]; 2] = [
+            [0.0, -theta],
+            [theta, 0.0]
+        ];
+        let e = a.expm();
+        println!("e = {e:?}");
+        let rotation = [
+            [theta.cos(), -theta.sin()],
+            [theta.sin(), theta.cos()]
+        ];
+        assert!((0..2).all(|r| (0..2).all(|c| (e[r][c] - rotation[r][c]).abs() < 1e-14)));
+
+        // Nilpotent part gives a finite series
+        let a: [[Complex<f64>; 2]; 2] = [
+            [Complex::new(0.0, 1.0), Complex::new(3.0, 0.0)],
+            [Complex::new(0.0, 0.0), Complex::new(0.0, 1.0)]
+        ];
+        let e = a.expm();
+        let ei = Complex::new(0.0, 1.0).exp();
+        let expected = [[ei, ei*3.0], [Complex::new(0.0, 0.0), ei]];
+        assert!((0..2).all(|r| (0..2).all(|c| (e[r][c] - expected[r][c]).norm() < 1e-13)));
+
+        // Non-finite elements give NaN rather than a panic
+        let e = [[1.0, f64::INFINITY], [0.0, 1.0]].expm();
+        assert!(e.iter().flatten().all(|en| en.is_nan()));
+        let e = [[f64::NAN, 0.0], [0.0, 1.0]].expm();
+        assert!(e.iter().flatten().all(|en| en.is_nan()));
+        let e = [[f64::MAX, 0.0], [f64::MAX, 1.0]].expm();
+        assert!(e.iter().flatten().all(|en| en.is_nan()));
+    }
+
+    #[test]
+    fn matrix_functions()
+    {
+        let a: [[f64; 3]; 3] = [
+            [4.0, 1.0, 0.5],
+            [1.0, 3.0, -1.0],
+            [0.0, 2.0, 7.0]
+        ];
+        let sqrt_a = a.sqrtm().unwrap();
+        println!("sqrt(a) = {sqrt_a:?}");
+        let sqrt_a_sqr: [[f64; 3]; 3] = sqrt_a.mul(sqrt_a);
+        assert!((0..3).all(|r| (0..3).all(|c| (sqrt_a_sqr[r][c] - a[r][c]).abs() < 1e-12)));
+
+        let log_a = a.logm().unwrap();
+        let exp_log_a = log_a.expm();
+        assert!((0..3).all(|r| (0..3).all(|c| (exp_log_a[r][c] - a[r][c]).abs() < 1e-12)));
+
+        // Cube root through the general matrix function
+        let cbrt_a = a.funm(|z| z.powf(1.0/3.0)).unwrap();
+        let cbrt_a_cube: [[Complex<f64>; 3]; 3] = cbrt_a.mul(cbrt_a).mul(cbrt_a);
+        assert!((0..3).all(|r| (0..3).all(|c| (cbrt_a_cube[r][c] - a[r][c]).norm() < 1e-12)));
+
+        // Nearly repeated eigenvalues, still far enough apart for the recurrence
+        let a: [[f64; 3]; 3] = [
+            [1.0, 2.0, 0.5],
+            [0.0, 1.0 + 1e-4, 3.0],
+            [0.0, 0.0, 2.0]
+        ];
+        let exp_a = a.funm(|z| z.exp()).unwrap();
+        let expected = a.expm();
+        assert!((0..3).all(|r| (0..3).all(|c| (exp_a[r][c] - expected[r][c]).norm() < 1e-9*expected[r][c].abs().max(1.0))));
+        let a: [[f64; 2]; 2] = [
+            [1.0, 1.0],
+            [0.0, 1.0 + 1e-10]
+        ];
+        assert_eq!(a.try_funm(|z| z.exp()), Err(LinalgError::RepeatedEigenvalues));
+
+        // Rotation by half the angle
+        let theta: f64 = 2.0;
+        let rotation: [[f64; 2]; 2] = [
+            [theta.cos(), -theta.sin()],
+            [theta.sin(), theta.cos()]
+        ];
+        let half = rotation.sqrtm().unwrap();
+        assert!((half[0][0] - (theta/2.0).cos()).abs() < 1e-12 && (half[1][0] - (theta/2.0).sin()).abs() < 1e-12);
+        let log_rotation = rotation.logm().unwrap();
+        assert!(log_rotation[0][0].abs() < 1e-12 && (log_rotation[1][0] - theta).abs() < 1e-12);
+
+        // Eigenvalues on the negative real axis
+        let a: [[f64; 2]; 2] = [
+            [-1.0, 0.0],
+            [0.0, 2.0]
+        ];
+        assert_eq!(a.sqrtm(), None);
+        assert_eq!(a.logm(), None);
+
+        // Non-normal, with eigenvalues -2 and 1 ± i, so the Schur form carries rounding errors in the imaginary parts
+        let a: [[f64; 3]; 3] = [
+            [-9.0, 7.0, -4.0],
+            [-13.0, 11.0, -6.0],
+            [-5.0, 5.0, -2.0]
+        ];
+        assert_eq!(a.try_sqrtm(), Err(LinalgError::EigenvalueOnNegativeRealAxis));
+        assert_eq!(a.try_logm(), Err(LinalgError::EigenvalueOnNegativeRealAxis));
+    }
+
+    #[test]
+    fn powi()
+    {
+        // Two-state Markov chain
+        let p = [
+            [0.9, 0.1],
+            [0.5, 0.5]
+        ];
+        let p10 = p.powi(10).unwrap();
+        println!("p^10 = {p10:?}");
+        let p5 = p.mul(p).mul(p).mul(p).mul(p);
+        let expected: [[f64; 2]; 2] = p5.mul(p5);
+        assert!((0..2).all(|r| (0..2).all(|c| (p10[r][c] - expected[r][c]).abs() < 1e-14)));
+
+        let a = [
+            [2.0, 1.0],
+            [1.0, 1.0]
+        ];
+        assert_eq!(a.powi(0), Some([[1.0, 0.0], [0.0, 1.0]]));
+        assert_eq!(a.powi(-2), Some([[2.0, -3.0], [-3.0, 5.0]]));
+        assert_eq!([[1.0, 2.0], [2.0, 4.0]].powi(-1), None);
+
+        // Fibonacci numbers over the integers
+        let fib: [[i64; 2]; 2] = [
+            [1, 1],
+            [1, 0]
+        ];
+        assert_eq!(fib.powu(0), [[1, 0], [0, 1]]);
+        assert_eq!(fib.powu(90), [
+            [4660046610375530309, 2880067194370816120],
+            [2880067194370816120, 1779979416004714189]
+        ]);
+    }
+
+    #[test]
+    fn char_poly()
+    {
+        let a = [
+            [2, -1, 0],
+            [1, 3, 4],
+            [0, 5, -2]
+        ];
+        let p = a.char_poly();
+        println!("p = {p:?}");
+        assert_eq!(p, [1, -3, -23, 54]);
+        assert_eq!(a.poly_eval(&p), [[0; 3]; 3]);
+        assert_eq!(a.poly_eval(&[1, 0, 0]), a.mul(a));
+    }
+
+    #[test]
+    fn norm()
+    {
+        let a: [[f64; 3]; 2] = [
+            [1.0, -2.0, 2.0],
+            [0.0, 3.0, -4.0]
+        ];
+        println!("||a||_F = {}, ||a||_2 = {:?}", a.norm_fro(), a.norm_2());
+        assert_eq!(a.norm_fro(), 34.0f64.sqrt());
+        assert_eq!(a.norm_1(), 6.0);
+        assert_eq!(a.norm_inf(), 7.0);
+        // aaᵀ = [[9, -14], [-14, 25]], so σ₁² + σ₂² = 34 and σ₁σ₂ = √29
+        assert!((a.norm_2().unwrap() - ((34.0 + 1040.0f64.sqrt())/2.0).sqrt()).abs() < 1e-12);
+        assert!((a.norm_nuclear().unwrap() - (34.0 + 2.0*29.0f64.sqrt()).sqrt()).abs() < 1e-12);
+
+        let b: [[Complex<f64>; 2]; 2] = [
+            [Complex::new(0.0, 3.0), Complex::new(0.0, 0.0)],
+            [Complex::new(0.0, 0.0), Complex::new(-4.0, 0.0)]
+        ];
+        assert_eq!(b.norm_fro(), 5.0);
+        assert!((b.norm_2().unwrap() - 4.0).abs() < 1e-12);
+        assert!((b.norm_nuclear().unwrap() - 7.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn cond()
+    {
+        // Hilbert matrix
+        let h: [[f64; 4]; 4] = array_init::array_init(|r| array_init::array_init(|c| 1.0/(r + c + 1) as f64));
+        let cond_2 = h.cond().unwrap();
+        let cond_1 = h.cond_est();
+        println!("cond_2 = {cond_2}, cond_1 = {cond_1}");
+        assert!((cond_2/15513.738738929 - 1.0).abs() < 1e-9);
+        // The exact 1-norm condition number of the 4×4 Hilbert matrix is 28375
+        assert!((28375.0/3.0..=28375.0*(1.0 + 1e-9)).contains(&cond_1));
+
+        let hi = h.inv_checked(1e-6).unwrap();
+        let hhi: [[f64; 4]; 4] = h.mul(hi);
+        assert!((0..4).all(|r| (0..4).all(|c| (hhi[r][c] - if r == c {1.0} else {0.0}).abs() < 1e-9)));
+        assert_eq!(h.inv_checked(1e-3), None);
+
+        let a = [
+            [1.0, 2.0],
+            [2.0, 4.0 + 1e-15]
+        ];
+        assert!(a.inv().is_some());
+        assert_eq!(a.inv_checked(1e-12), None);
+        assert_eq!([[1.0, 2.0], [2.0, 4.0]].cond_est(), f64::INFINITY);
+    }
+
+    #[test]
+    fn linalg_error()
+    {
+        let a = [
+            [1.0, 2.0],
+            [2.0, 4.0]
+        ];
+        assert_eq!(a.try_inv(), Err(LinalgError::Singular));
+        assert_eq!(a.try_solve([1.0, 1.0]), Err(LinalgError::Singular));
+        assert_eq!(a.try_cholesky(), Err(LinalgError::NotPositiveDefinite));
+        assert_eq!(a.try_powi(-2), Err(LinalgError::Singular));
+        assert_eq!([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]].try_eig_with(f64::EPSILON, 0), Err(LinalgError::NoConvergence));
+        assert_eq!([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]].try_schur_with(f64::EPSILON, 0).map(|_| ()), Err(LinalgError::NoConvergence));
+        assert_eq!([[1.0, 2.0], [0.0, 0.0]].try_solve_upper_triangular([1.0, 1.0]), Err(LinalgError::Singular));
+        assert_eq!([[0.0, 0.0], [2.0, 1.0]].try_solve_lower_triangular_transpose([1.0, 1.0]), Err(LinalgError::Singular));
+
+        let a = [
+            [3.0, 0.0],
+            [0.0, -4.0]
+        ];
+        assert_eq!(a.try_norm_2(), Ok(4.0));
+        assert_eq!(a.try_norm_nuclear(), Ok(7.0));
+        assert_eq!(a.try_cond(), Ok(4.0/3.0));
+        assert_eq!(a.try_pinv_with(1e-9), a.pinv().ok_or(LinalgError::NoConvergence));
+
+        let a = [
+            [1.0, 1.0],
+            [1.0, 1.0 + 1e-14]
+        ];
+        let error = a.try_inv_checked(1e-12).unwrap_err();
+        println!("error = {error}");
+        assert_eq!(error, LinalgError::IllConditioned);
+        assert!(a.try_inv().is_ok());
+
+        let a = [
+            [-4.0, 0.0],
+            [0.0, 1.0]
+        ];
+        assert_eq!(a.try_sqrtm(), Err(LinalgError::EigenvalueOnNegativeRealAxis));
+        assert_eq!(a.try_logm(), Err(LinalgError::EigenvalueOnNegativeRealAxis));
+        assert_eq!([[2.0, 1.0], [0.0, 2.0f64]].try_funm(|z| z.exp()), Err(LinalgError::RepeatedEigenvalues));
+        let b = [1.0, 2.0, 3.0];
+        assert_eq!([[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]].try_least_squares(b), Err(LinalgError::RankDeficient));
+
+        let a: [[f64; 3]; 2] = [
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0]
+        ];
+        assert_eq!(a.try_qr_householder(), Err(LinalgError::DimensionMismatch));
+        assert_eq!(a.try_least_squares([1.0, 2.0]), Err(LinalgError::DimensionMismatch));
+        assert!([[1.0, 2.0], [3.0, 4.0f64]].try_qr_householder().is_ok());
+    }
+
+    #[test]
+    fn mat()
+    {
+        // Same system as above, integrated with operators
+        let a = Mat([
+            [-0.5, -0.1],
+            [10.0, -0.2]
+        ]);
+        let b = Mat([
+            [1.0],
+            [0.0]
+        ]);
+        let c = Mat([
+            [0.0, 1.0]
+        ]);
+        let dt: f32 = 40.0/1000.0;
+
+        let mut x = Mat([[0.0f32]; 2]);
+        let mut x_array: [[f32; 1]; 2] = Matrix::empty();
+        let u = Mat([[1.0f32]]);
+        for _ in 0..1000
+        {
+            x += (a*x + b*u)*dt;
+            x_array = x_array.add(a.0.mul(x_array).add(b.0.mul(1.0)).mul(dt));
+        }
+        println!("y = {:?}", c*x);
+        assert_eq!(x.into_array(), x_array);
+
+        let mut m: Mat<f64, 2, 2> = [[1.0, 2.0], [3.0, 4.0]].into();
+        assert_eq!(m*[1.0, -1.0], [-1.0, -1.0]);
+        assert_eq!(-m - m, m*-2.0);
+        m[(0, 1)] = 0.0;
+        m *= Mat([[0.0, 1.0], [1.0, 0.0]]);
+        m /= 2.0;
+        assert_eq!(<[[f64; 2]; 2]>::from(m), [[0.0, 0.5], [2.0, 1.5]]);
+        assert_eq!(m.det(), -1.0);
+        assert_eq!(Mat::from_ref(&[[1, 2]])[(0, 1)], 2);
+    }
+
+    #[test]
+    fn vector_product()
+    {
+        let a: [[f64; 3]; 2] = [
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0]
+        ];
+        let x = [1.0, 0.0, -1.0];
+        let ax = a.mul(x);
+        println!("ax = {ax:?}");
+        assert_eq!(ax, [-2.0, -2.0]);
+        assert_eq!(a.mul(Column(x)), Column(ax));
+        assert_eq!(Column::from(a.mul(Column(x).into_matrix())), Column(ax));
+
+        let u = Row([1.0, -1.0]);
+        assert_eq!(u*a, Row([-3.0, -3.0, -3.0]));
+        assert_eq!(u*Mat(a), Row([-3.0, -3.0, -3.0]));
+        assert_eq!(Mat(a)*Column(x), Column(ax));
+        assert_eq!(u*Column(ax), u.dot(ax));
+        assert_eq!(Column(ax)*u, [[-2.0, 2.0], [-2.0, 2.0]]);
+        assert_eq!(u.transpose().into_matrix(), [[1.0], [-1.0]]);
+        assert_eq!(<[[f64; 2]; 1]>::from(u), [[1.0, -1.0]]);
+    }
+
+    #[test]
+    fn in_place()
+    {
+        let mut a: [[f64; 3]; 3] = [
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 9.0]
+        ];
+        let b: [[f64; 3]; 3] = [
+            [0.5, 0.0, -1.0],
+            [2.0, 1.0, 0.0],
+            [0.0, -3.0, 1.5]
+        ];
+
+        let expected = a.add(b).sub(b).mul(2.0).transpose();
+        a.add_assign(b);
+        a.sub_assign(b);
+        a.scale_mut(2.0);
+        a.transpose_in_place();
+        assert_eq!(a, expected);
+        println!("{}", a.map(|ar| ar.map(|arc| arc.to_string()).join(", ")).join("\n"));
+    }
+}
\ No newline at end of file
@@ -19,6 +19,36 @@ pub mod eig;
 pub mod diag;
 pub mod qr_householder;
 pub mod div;
+pub mod lu;
+pub mod solve;
+pub mod det_bareiss;
+pub mod det_lu;
+pub mod adj_lu;
+pub mod cholesky;
+pub mod hessenberg;
+pub mod schur;
+pub mod symmetric_eig;
+pub mod svd;
+pub mod pinv;
+pub mod least_squares;
+pub mod triangular;
+pub mod qr_givens;
+pub mod qr_update;
+pub mod expm;
+pub mod funm;
+pub mod sqrtm;
+pub mod logm;
+pub mod pow;
+pub mod char_poly;
+pub mod poly_eval;
+pub mod norm;
+pub mod cond;
+pub mod inv_checked;
+pub mod mat;
+pub mod add_assign;
+pub mod sub_assign;
+pub mod scale_mut;
+pub mod transpose_in_place;
 
 pub use self::det::*;
 pub use self::submatrix::*;
@@ -38,6 +68,36 @@ pub use self::eig::*;
 pub use self::diag::*;
 pub use self::qr_householder::*;
 pub use self::div::*;
+pub use self::lu::*;
+pub use self::solve::*;
+pub use self::det_bareiss::*;
+pub use self::det_lu::*;
+pub use self::adj_lu::*;
+pub use self::cholesky::*;
+pub use self::hessenberg::*;
+pub use self::schur::*;
+pub use self::symmetric_eig::*;
+pub use self::svd::*;
+pub use self::pinv::*;
+pub use self::least_squares::*;
+pub use self::triangular::*;
+pub use self::qr_givens::*;
+pub use self::qr_update::*;
+pub use self::expm::*;
+pub use self::funm::*;
+pub use self::sqrtm::*;
+pub use self::logm::*;
+pub use self::pow::*;
+pub use self::char_poly::*;
+pub use self::poly_eval::*;
+pub use self::norm::*;
+pub use self::cond::*;
+pub use self::inv_checked::*;
+pub use self::mat::*;
+pub use self::add_assign::*;
+pub use self::sub_assign::*;
+pub use self::scale_mut::*;
+pub use self::transpose_in_place::*;
 
 pub trait Matrix: Sized
 {
@@ -113,7 +173,7 @@ pub trait Matrix: Sized
 /// # extern crate array_init;
 /// #
 /// let a: [[f64; 3]; 3] = matrix_init(|r, c| (r * c) as f64);
-///
+/// 
 /// assert!(arr.iter().enumerate().all(|(r, ar)| ar.iter().enumerate().all(|(c, &arc)| arc == (r * c) as f64)));
 /// ```
 pub fn matrix_init<F, T, const L: usize, const H: usize>(mut initializer: F) -> [[T; L]; H]
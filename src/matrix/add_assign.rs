@@ -0,0 +1,49 @@
+use std::ops::AddAssign;
+
+use crate::Matrix;
+
+pub trait MAddAssign<Rhs: Matrix>: Matrix
+{
+    /// Adds a matrix of equal dimensions to this one in place
+    /// 
+    /// A ← A + B
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rhs` - The addend matrix
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let mut a = [
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0]
+    /// ];
+    /// let b = [
+    ///     [4.0, 3.0],
+    ///     [2.0, 1.0]
+    /// ];
+    /// let s = a.add(b);
+    /// a.add_assign(b);
+    /// assert_eq!(a, s);
+    /// ```
+    fn add_assign(&mut self, rhs: Rhs);
+}
+
+impl<T1, T2, const H: usize, const L: usize> MAddAssign<[[T2; L]; H]> for [[T1; L]; H]
+where
+    Self: Matrix,
+    [[T2; L]; H]: Matrix,
+    T1: AddAssign<T2>
+{
+    fn add_assign(&mut self, rhs: [[T2; L]; H])
+    {
+        for (row, rhs_row) in self.iter_mut().zip(rhs)
+        {
+            for (x, rhs_x) in row.iter_mut().zip(rhs_row)
+            {
+                *x += rhs_x
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -1,11 +1,11 @@
-use std::ops::Neg;
+use std::ops::{Mul, Sub, Add};
 
-use num_traits::{One};
+use num_traits::{Zero, One};
 
 use crate::SquareMatrix;
 
 use super::matrix_init;
-use super::minor::Minor;
+use super::det::char_poly;
 
 pub trait Adj: SquareMatrix
 where
@@ -33,60 +33,32 @@ where
     fn adj(&self) -> Self::Output;
 }
 
-impl<F: One> Adj for [[F; 1]; 1]
+impl<F, const N: usize> Adj for [[F; N]; N]
 where
-    Self: SquareMatrix
+    Self: SquareMatrix,
+    F: Clone + Zero + One + Add<F, Output = F> + Sub<F, Output = F> + Mul<F, Output = F>
 {
     type Output = Self;
 
     fn adj(&self) -> Self::Output
     {
-        [[F::one()]]
-    }
-}
-
-impl<F: Neg<Output = F> + Clone> Adj for [[F; 2]; 2]
-where
-    Self: SquareMatrix
-{
-    type Output = Self;
-
-    fn adj(&self) -> Self::Output
-    {
-        [
-            [self[1][1].clone(), -self[0][1].clone()],
-            [-self[1][0].clone(), self[0][0].clone()]
-        ]
-    }
-}
-
-macro_rules! adj {
-    ($i:expr) => {
-        impl<F: One + Neg<Output = F>> Adj for [[F; $i]; $i]
-        where
-            Self: SquareMatrix + Minor<Output = F, Index = (usize, usize)>
+        // By Cayley-Hamilton, adj(A) = (-1)ⁿ⁺¹(Aⁿ⁻¹ + c₁Aⁿ⁻² + ... + cₙ₋₁I)
+        let c = char_poly(self);
+        let mut b: Self = matrix_init(|r, k| if r == k {F::one()} else {F::zero()});
+        for ck in c[1..N].iter()
+        {
+            b = matrix_init(|r, k| (0..N)
+                .map(|i| self[r][i].clone()*b[i][k].clone())
+                .fold(if r == k {ck.clone()} else {F::zero()}, |a, b| a + b)
+            );
+        }
+        if N.is_multiple_of(2)
         {
-            type Output = Self;
-        
-            fn adj(&self) -> Self::Output
-            {
-                matrix_init(|r, c| if (r+c)%2 == 0 {F::one()} else {-F::one()}*self.minor((c, r)))
-            }
+            b.map(|br| br.map(|brk| F::zero() - brk))
         }
-    };
+        else
+        {
+            b
+        }
+    }
 }
-
-adj!(3);
-adj!(4);
-adj!(5);
-adj!(6);
-adj!(7);
-adj!(8);
-adj!(9);
-adj!(10);
-adj!(11);
-adj!(12);
-adj!(13);
-adj!(14);
-adj!(15);
-adj!(16);
\ No newline at end of file
@@ -0,0 +1,61 @@
+use num_complex::ComplexFloat;
+
+use crate::{SquareMatrix, lu::{lu_in_place, lu_det}, triangular::{forward_substitute, back_substitute}};
+
+use super::matrix_init;
+
+pub trait AdjLU: SquareMatrix
+where
+    Self::Output: SquareMatrix
+{
+    type Output;
+
+    /// Returns the adjugate matrix of the given square matrix using LU-decomposition with partial pivoting
+    /// 
+    /// adj(A) = |A|A⁻¹
+    /// 
+    /// Runs in O(n³). If a pivot is exactly zero, each cofactor is instead computed as the determinant of A with a row replaced by a unit vector,
+    /// so singular matrices are handled too.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, 2.0],
+    ///     [2.0, 4.0]
+    /// ];
+    /// let aa = [
+    ///     [4.0, -2.0],
+    ///     [-2.0, 1.0]
+    /// ];
+    /// assert_eq!(a.adj_lu(), aa);
+    /// ```
+    fn adj_lu(&self) -> Self::Output;
+}
+
+impl<F: ComplexFloat, const N: usize> AdjLU for [[F; N]; N]
+where
+    Self: SquareMatrix
+{
+    type Output = Self;
+
+    fn adj_lu(&self) -> Self::Output
+    {
+        let mut lu = *self;
+        let perm = lu_in_place(&mut lu);
+        if (0..N).all(|i| !lu[i][i].is_zero())
+        {
+            let det = lu_det(&lu, &perm);
+            let mut x: Self = matrix_init(|r, c| if perm[r] == c {det} else {F::zero()});
+            forward_substitute(|r, c| lu[r][c], &mut x, true);
+            back_substitute(|r, c| lu[r][c], &mut x, false);
+            return x
+        }
+        matrix_init(|r, c| {
+            let mut ac = *self;
+            ac[c] = array_init::array_init(|k| if k == r {F::one()} else {F::zero()});
+            let perm = lu_in_place(&mut ac);
+            lu_det(&ac, &perm)
+        })
+    }
+}
\ No newline at end of file
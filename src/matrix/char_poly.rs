@@ -0,0 +1,44 @@
+use std::ops::{Mul, Sub, Add};
+
+use num_traits::{Zero, One};
+
+use crate::SquareMatrix;
+
+use super::det;
+
+pub trait CharPoly: SquareMatrix
+{
+    type Output;
+
+    /// Returns the coefficients of the characteristic polynomial of the given matrix, starting with the leading one
+    /// 
+    /// |λI - A| = λⁿ + c₁λⁿ⁻¹ + ... + cₙ
+    /// 
+    /// The computation needs no division, so it is exact for integer matrices and other commutative rings.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1, 2],
+    ///     [3, 4]
+    /// ];
+    /// assert_eq!(a.char_poly(), [1, -5, -2]);
+    /// ```
+    fn char_poly(&self) -> Self::Output;
+}
+
+impl<F, const N: usize> CharPoly for [[F; N]; N]
+where
+    Self: SquareMatrix,
+    [(); N + 1]:,
+    F: Clone + Zero + One + Add<F, Output = F> + Sub<F, Output = F> + Mul<F, Output = F>
+{
+    type Output = [F; N + 1];
+
+    fn char_poly(&self) -> Self::Output
+    {
+        let c = det::char_poly(self);
+        array_init::array_init(|i| c[i].clone())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,168 @@
+use num_complex::ComplexFloat;
+use num_traits::{Float, Zero};
+
+use crate::{SquareMatrix, Herm, LinalgError, triangular::{forward_substitute, back_substitute}};
+
+pub trait Cholesky: SquareMatrix
+{
+    type Output;
+
+    /// Returns the lower-triangular Cholesky factor if the matrix is positive definite
+    /// 
+    /// A = LLᴴ
+    /// 
+    /// The matrix is assumed to be symmetric or Hermitian, so only its lower triangle is read.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [4.0, 2.0],
+    ///     [2.0, 2.0]
+    /// ];
+    /// let l = [
+    ///     [2.0, 0.0],
+    ///     [1.0, 1.0]
+    /// ];
+    /// assert_eq!(a.cholesky(), Some(l));
+    /// 
+    /// // Returns none if matrix is not positive definite
+    /// let a = [
+    ///     [1.0, 2.0],
+    ///     [2.0, 1.0]
+    /// ];
+    /// assert_eq!(a.cholesky(), None);
+    /// ```
+    fn cholesky(&self) -> Option<Self::Output>;
+
+    /// Returns the Cholesky-decomposition of the given matrix, or an error if it is not positive-definite
+    /// 
+    /// A = LLᴴ
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a = [
+    ///     [1.0, 2.0],
+    ///     [2.0, 1.0]
+    /// ];
+    /// assert_eq!(a.try_cholesky(), Err(LinalgError::NotPositiveDefinite));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_cholesky(&self) -> Result<Self::Output, LinalgError>
+    {
+        self.cholesky().ok_or(LinalgError::NotPositiveDefinite)
+    }
+}
+
+impl<F: ComplexFloat, const N: usize> Cholesky for [[F; N]; N]
+where
+    Self: SquareMatrix
+{
+    type Output = Self;
+
+    fn cholesky(&self) -> Option<Self::Output>
+    {
+        let mut l = [[F::zero(); N]; N];
+        for j in 0..N
+        {
+            let d = (0..j)
+                .map(|k| (l[j][k]*l[j][k].conj()).re())
+                .fold(self[j][j].re(), |a, b| a - b);
+            if Float::is_nan(d) || d <= Zero::zero()
+            {
+                return None
+            }
+            l[j][j] = F::from(Float::sqrt(d)).unwrap();
+            for i in j + 1..N
+            {
+                l[i][j] = (0..j)
+                    .map(|k| l[i][k]*l[j][k].conj())
+                    .fold(self[i][j], |a, b| a - b)/l[j][j];
+            }
+        }
+        Some(l)
+    }
+}
+
+pub trait CholeskySolve<Rhs>: SquareMatrix
+{
+    type Output;
+
+    /// Returns the solution of the linear system if the matrix is positive definite
+    /// 
+    /// A⁻¹b = (Lᴴ)⁻¹L⁻¹b
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rhs` - A vector or a matrix with height equal this matrix's length
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [4.0, 2.0],
+    ///     [2.0, 2.0]
+    /// ];
+    /// let b = [2.0, 0.0];
+    /// assert_eq!(a.cholesky_solve(b), Some([1.0, -1.0]));
+    /// ```
+    fn cholesky_solve(&self, rhs: Rhs) -> Option<Self::Output>;
+
+    /// Returns the solution of the linear system by Cholesky-decomposition, or an error if the matrix is not positive-definite
+    /// 
+    /// A⁻¹b
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a = [
+    ///     [1.0, 2.0],
+    ///     [2.0, 1.0]
+    /// ];
+    /// let b = [1.0, 2.0];
+    /// assert_eq!(a.try_cholesky_solve(b), Err(LinalgError::NotPositiveDefinite));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_cholesky_solve(&self, rhs: Rhs) -> Result<Self::Output, LinalgError>
+    {
+        self.cholesky_solve(rhs).ok_or(LinalgError::NotPositiveDefinite)
+    }
+}
+
+impl<F: ComplexFloat, const N: usize> CholeskySolve<[F; N]> for [[F; N]; N]
+where
+    Self: CholeskySolve<[[F; 1]; N], Output = [[F; 1]; N]>
+{
+    type Output = [F; N];
+
+    fn cholesky_solve(&self, rhs: [F; N]) -> Option<Self::Output>
+    {
+        self.cholesky_solve(rhs.map(|b| [b])).map(|x| x.map(|[x]| x))
+    }
+}
+
+impl<F: ComplexFloat, const N: usize, const K: usize> CholeskySolve<[[F; K]; N]> for [[F; N]; N]
+where
+    Self: Cholesky<Output = Self> + Herm<Output = Self>
+{
+    type Output = [[F; K]; N];
+
+    fn cholesky_solve(&self, rhs: [[F; K]; N]) -> Option<Self::Output>
+    {
+        let l = self.cholesky()?;
+        let mut x = rhs;
+        let lh = l.herm();
+        forward_substitute(|r, c| l[r][c], &mut x, false);
+        back_substitute(|r, c| lh[r][c], &mut x, false);
+        Some(x)
+    }
+}
@@ -0,0 +1,167 @@
+use num_complex::ComplexFloat;
+use num_traits::{Float, NumCast, Zero, One};
+
+use crate::{SquareMatrix, Svd, MNorm, LinalgError, lu::lu_in_place, triangular::{forward_substitute, back_substitute}};
+
+/// Limit on the number of iterations of the 1-norm estimator
+const MAX_ESTIMATOR_ITERATIONS: usize = 5;
+
+pub trait Cond: SquareMatrix
+{
+    type Output;
+
+    /// Returns the condition number of the given matrix in the spectral norm, if its singular value decomposition converges
+    /// 
+    /// κ₂(A) = σ₁/σₙ
+    /// 
+    /// Singular matrices have infinite condition number.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, 0.0],
+    ///     [0.0, 1e-6]
+    /// ];
+    /// assert_eq!(a.cond(), Some(1e6));
+    /// ```
+    fn cond(&self) -> Option<Self::Output>;
+
+    /// Returns the condition number of the given matrix in the spectral norm, or an error if its singular value decomposition does not converge
+    /// 
+    /// κ₂(A) = σ₁/σₙ
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a = [
+    ///     [1.0, 0.0],
+    ///     [0.0, 1e-6]
+    /// ];
+    /// assert_eq!(a.try_cond()?, 1e6);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_cond(&self) -> Result<Self::Output, LinalgError>
+    {
+        self.cond().ok_or(LinalgError::NoConvergence)
+    }
+
+    /// Returns an estimate of the condition number of the given matrix in the 1-norm
+    /// 
+    /// κ₁(A) = ||A||₁||A⁻¹||₁
+    /// 
+    /// ||A⁻¹||₁ is estimated from the LU-decomposition by the Hager-Higham method, without forming the inverse.
+    /// The estimate is a lower bound, and is usually within a factor of 3 of the actual value.
+    /// Singular matrices have infinite condition number.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, 1.0],
+    ///     [1.0, 1.0 + 1e-9]
+    /// ];
+    /// assert!(a.cond_est() > 1e9);
+    /// ```
+    fn cond_est(&self) -> Self::Output;
+}
+
+impl<F: ComplexFloat, const N: usize> Cond for [[F; N]; N]
+where
+    Self: SquareMatrix + Svd<OutputS = Self> + MNorm<Output = F::Real>
+{
+    type Output = F::Real;
+
+    fn cond(&self) -> Option<Self::Output>
+    {
+        let (_, s, _) = self.svd()?;
+        let (s_max, s_min) = (s[0][0].abs(), s[N - 1][N - 1].abs());
+        Some(if s_min.is_zero() {Float::infinity()} else {s_max/s_min})
+    }
+
+    fn cond_est(&self) -> Self::Output
+    {
+        let mut lu = *self;
+        let perm = lu_in_place(&mut lu);
+        if (0..N).any(|i| lu[i][i].is_zero())
+        {
+            return Float::infinity()
+        }
+        self.norm_1()*inv_norm_1_estimate(&lu, &perm)
+    }
+}
+
+/// Estimates ||A⁻¹||₁ from the LU-decomposition of A, as given by `lu_in_place`, which must have no zero pivots.
+pub(crate) fn inv_norm_1_estimate<F: ComplexFloat, const N: usize>(lu: &[[F; N]; N], perm: &[usize; N]) -> F::Real
+{
+    let norm_1 = |x: &[[F; 1]; N]| x.iter()
+        .map(|[xi]| xi.abs())
+        .fold(F::Real::zero(), |a, b| a + b);
+    // A⁻¹b
+    let solve = |b: [F; N]| {
+        let mut x: [[F; 1]; N] = array_init::array_init(|r| [b[perm[r]]]);
+        forward_substitute(|r, c| lu[r][c], &mut x, true);
+        back_substitute(|r, c| lu[r][c], &mut x, false);
+        x
+    };
+    // A⁻ᴴb
+    let solve_herm = |b: [F; N]| {
+        let mut y: [[F; 1]; N] = b.map(|bi| [bi]);
+        forward_substitute(|r, c| lu[c][r].conj(), &mut y, false);
+        back_substitute(|r, c| lu[c][r].conj(), &mut y, true);
+        let mut x = [[F::zero()]; N];
+        for (&pr, yr) in perm.iter().zip(y)
+        {
+            x[pr] = yr;
+        }
+        x
+    };
+
+    let n = F::from(N).unwrap();
+    let mut b = [F::one()/n; N];
+    let mut estimate = F::Real::zero();
+    for k in 0..MAX_ESTIMATOR_ITERATIONS
+    {
+        let y = solve(b);
+        let y_norm = norm_1(&y);
+        if k > 0 && y_norm <= estimate
+        {
+            break
+        }
+        estimate = y_norm;
+        let sign = y.map(|[yi]| if yi.is_zero() {F::one()} else {yi/F::from(yi.abs()).unwrap()});
+        let z = solve_herm(sign);
+        let (j, z_max) = z.iter()
+            .map(|[zi]| zi.abs())
+            .enumerate()
+            .fold((0, F::Real::zero()), |a, b| if b.1 > a.1 {b} else {a});
+        let ztb = z.iter()
+            .zip(b)
+            .map(|([zi], bi)| zi.conj()*bi)
+            .fold(F::zero(), |a, b| a + b)
+            .re();
+        if z_max <= ztb
+        {
+            break
+        }
+        b = array_init::array_init(|i| if i == j {F::one()} else {F::zero()});
+    }
+
+    // Alternative estimate, which catches the cases where the iteration is fooled
+    if N > 1
+    {
+        let n_minus_one = <F::Real as NumCast>::from(N - 1).unwrap();
+        let alternating: [F; N] = array_init::array_init(|i| {
+            let bi = F::from(F::Real::one() + <F::Real as NumCast>::from(i).unwrap()/n_minus_one).unwrap();
+            if i % 2 == 0 {bi} else {-bi}
+        });
+        let three = <F::Real as NumCast>::from(3*N).unwrap();
+        let two = F::Real::one() + F::Real::one();
+        estimate = Float::max(estimate, two*norm_1(&solve(alternating))/three);
+    }
+    estimate
+}
\ No newline at end of file
@@ -1,9 +1,8 @@
 use std::ops::{Mul, Sub, Add};
 
-use crate::SquareMatrix;
+use num_traits::{Zero, One};
 
-use super::Matrix;
-use super::minor::Minor;
+use crate::SquareMatrix;
 
 pub trait Det: SquareMatrix
 {
@@ -25,59 +24,51 @@ pub trait Det: SquareMatrix
     fn det(&self) -> Self::Output;
 }
 
-impl<F: Clone> Det for [[F; 1]; 1]
-where Self: SquareMatrix
+impl<F, const N: usize> Det for [[F; N]; N]
+where
+    Self: SquareMatrix,
+    F: Clone + Zero + One + Add<F, Output = F> + Sub<F, Output = F> + Mul<F, Output = F>
 {
     type Output = F;
 
     fn det(&self) -> Self::Output
     {
-        self[0][0].clone()
+        let c = char_poly(self).pop().unwrap();
+        if N.is_multiple_of(2) {c} else {F::zero() - c}
     }
 }
 
-impl<F: Clone + Mul<F, Output = F> + Sub<F, Output = F>> Det for [[F; 2]; 2]
-where Self: SquareMatrix
+/// Returns the coefficients of the characteristic polynomial |λI - A|, starting with the leading one.
+/// 
+/// Uses the Samuelson-Berkowitz algorithm, which needs no division and runs in O(n⁴),
+/// so it is exact over any commutative ring.
+pub(crate) fn char_poly<F, const N: usize>(a: &[[F; N]; N]) -> Vec<F>
+where
+    F: Clone + Zero + One + Add<F, Output = F> + Sub<F, Output = F> + Mul<F, Output = F>
 {
-    type Output = F;
-
-    fn det(&self) -> Self::Output
+    let mut p = vec![F::one()];
+    for r in 0..N
     {
-        self[0][0].clone()*self[1][1].clone() - self[0][1].clone()*self[1][0].clone()
-    }
-}
-
-macro_rules! det {
-    ($i:expr) => {
-        impl<F> Det for [[F; $i]; $i]
-        where
-            F: Clone + Mul<F, Output = F> + Add<F, Output = F>,
-            Self: SquareMatrix + Minor<Output = F, Index = (usize, usize)>
+        // First column of the Toeplitz matrix: 1, -aᵣᵣ, -RS, -RAS, ..., -RAʳ⁻¹S
+        let mut t = vec![F::one(), F::zero() - a[r][r].clone()];
+        let mut v: Vec<F> = (0..r).map(|i| a[i][r].clone()).collect();
+        for _ in 0..r
         {
-            type Output = F;
-        
-            fn det(&self) -> Self::Output
-            {
-                (0..self.length())
-                    .map(|i| self[i][0].clone()*self.minor((i, 0)))
-                    .reduce(|a, b| a + b)
-                    .unwrap()
-            }
+            let rv = (0..r)
+                .map(|j| a[r][j].clone()*v[j].clone())
+                .fold(F::zero(), |a, b| a + b);
+            t.push(F::zero() - rv);
+            v = (0..r)
+                .map(|i| (0..r)
+                    .map(|j| a[i][j].clone()*v[j].clone())
+                    .fold(F::zero(), |a, b| a + b)
+                ).collect();
         }
-    };
+        p = (0..r + 2)
+            .map(|i| (0..=i.min(r))
+                .map(|j| t[i - j].clone()*p[j].clone())
+                .fold(F::zero(), |a, b| a + b)
+            ).collect();
+    }
+    p
 }
-
-det!(3);
-det!(4);
-det!(5);
-det!(6);
-det!(7);
-det!(8);
-det!(9);
-det!(10);
-det!(11);
-det!(12);
-det!(13);
-det!(14);
-det!(15);
-det!(16);
\ No newline at end of file
@@ -0,0 +1,65 @@
+use std::ops::{Mul, Sub, Div};
+
+use num_traits::{Zero, One};
+
+use crate::SquareMatrix;
+
+pub trait DetBareiss: SquareMatrix
+{
+    type Output;
+
+    /// Returns the determinant of the given matrix using fraction-free Bareiss elimination
+    /// 
+    /// |A|
+    /// 
+    /// Every division made is exact, so there is no rounding for integers, rationals or big integers.
+    /// Runs in O(n³).
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [2, -1, 0],
+    ///     [1, 3, 2],
+    ///     [0, 5, -4]
+    /// ];
+    /// assert_eq!(a.det_bareiss(), a.det());
+    /// ```
+    fn det_bareiss(&self) -> Self::Output;
+}
+
+impl<F, const N: usize> DetBareiss for [[F; N]; N]
+where
+    Self: SquareMatrix,
+    F: Clone + Zero + One + Sub<F, Output = F> + Mul<F, Output = F> + Div<F, Output = F>
+{
+    type Output = F;
+
+    fn det_bareiss(&self) -> Self::Output
+    {
+        let mut a = self.clone();
+        let mut odd = false;
+        let mut prev = F::one();
+        for k in 0..N
+        {
+            match (k..N).find(|&i| !a[i][k].is_zero())
+            {
+                Some(p) => if p != k
+                {
+                    a.swap(p, k);
+                    odd = !odd;
+                },
+                None => return F::zero()
+            }
+            for i in k + 1..N
+            {
+                for j in k + 1..N
+                {
+                    a[i][j] = (a[k][k].clone()*a[i][j].clone() - a[i][k].clone()*a[k][j].clone())/prev.clone();
+                }
+            }
+            prev = a[k][k].clone();
+        }
+        if odd {F::zero() - prev} else {prev}
+    }
+}
@@ -0,0 +1,40 @@
+use num_complex::ComplexFloat;
+
+use crate::{SquareMatrix, lu::{lu_in_place, lu_det}};
+
+pub trait DetLU: SquareMatrix
+{
+    type Output;
+
+    /// Returns the determinant of the given matrix using LU-decomposition with partial pivoting
+    /// 
+    /// |A|
+    /// 
+    /// Runs in O(n³), and is better conditioned than [`Det`](crate::Det) for floating-point numbers.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [2.0, -1.0, 0.0],
+    ///     [1.0, 3.0, 2.0],
+    ///     [0.0, 5.0, -4.0]
+    /// ];
+    /// assert!((a.det_lu() - a.det()).abs() < 1e-12);
+    /// ```
+    fn det_lu(&self) -> Self::Output;
+}
+
+impl<F: ComplexFloat, const N: usize> DetLU for [[F; N]; N]
+where
+    Self: SquareMatrix
+{
+    type Output = F;
+
+    fn det_lu(&self) -> Self::Output
+    {
+        let mut lu = *self;
+        let perm = lu_in_place(&mut lu);
+        lu_det(&lu, &perm)
+    }
+}
\ No newline at end of file
@@ -1,18 +1,62 @@
 use num_complex::{Complex};
-use num_traits::{Float};
+use num_traits::{Float, FloatConst, Zero, One};
 
-use crate::{MMul, Diag, QRHouseholder, Matrix};
+use crate::{Matrix, SquareMatrix, LinalgError, hessenberg::norm, schur::{schur_in_place, real_schur_in_place, real_schur_eigenvalues, ITERATIONS_PER_EIGENVALUE}};
 
-const ITERATIONS: usize = 1000;
-
-pub trait Eig: Matrix
+pub trait Eig: SquareMatrix
 {
     type Output;
+    type Tolerance;
+
+    /// Returns the eigenvalues of a matrix if the shifted QR-iteration converges
+    /// 
+    /// eig(A)
+    /// 
+    /// Uses machine precision as tolerance, and at most 30 QR-steps per eigenvalue.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0]
+    /// ];
+    /// let eig_a = a.eig().unwrap();
+    /// ```
+    fn eig(&self) -> Option<Self::Output>;
+
+    /// Returns the eigenvalues of a matrix, or an error if the shifted QR-iteration does not converge
+    /// 
+    /// eig(A)
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a: [[f64; 2]; 2] = [
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0]
+    /// ];
+    /// let eig_a = a.try_eig()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_eig(&self) -> Result<Self::Output, LinalgError>
+    {
+        self.eig().ok_or(LinalgError::NoConvergence)
+    }
 
-    /// Returns an approximation of the eigenvalues of a matrix
+    /// Returns the eigenvalues of a matrix if the shifted QR-iteration converges within the given limits
     /// 
     /// eig(A)
     /// 
+    /// # Arguments
+    /// 
+    /// * `tolerance` - Relative size of a subdiagonal element below which it is considered zero
+    /// * `max_iterations` - Maximum number of QR-steps in total
+    /// 
     /// # Examples
     /// 
     /// ```rust
@@ -20,52 +64,224 @@ pub trait Eig: Matrix
     ///     [1.0, 2.0],
     ///     [3.0, 4.0]
     /// ];
-    /// let eig_a = eig(a);
+    /// let eig_a = a.eig_with(1e-9, 100).unwrap();
+    /// ```
+    fn eig_with(&self, tolerance: Self::Tolerance, max_iterations: usize) -> Option<Self::Output>;
+
+    /// Returns the eigenvalues of a matrix, or an error if the shifted QR-iteration does not converge within the given limits
+    /// 
+    /// eig(A)
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a = [
+    ///     [1.0, 2.0, 3.0],
+    ///     [4.0, 5.0, 6.0],
+    ///     [7.0, 8.0, 10.0]
+    /// ];
+    /// assert_eq!(a.try_eig_with(1e-9, 0), Err(LinalgError::NoConvergence));
+    /// # Ok(())
+    /// # }
     /// ```
-    fn eig(&self) -> Self::Output;
+    fn try_eig_with(&self, tolerance: Self::Tolerance, max_iterations: usize) -> Result<Self::Output, LinalgError>
+    {
+        self.eig_with(tolerance, max_iterations).ok_or(LinalgError::NoConvergence)
+    }
+}
+
+impl<F: Float + FloatConst, const N: usize> Eig for [[Complex<F>; N]; N]
+where
+    Self: SquareMatrix
+{
+    type Output = [Complex<F>; N];
+    type Tolerance = F;
+
+    fn eig(&self) -> Option<Self::Output>
+    {
+        self.eig_with(F::epsilon(), ITERATIONS_PER_EIGENVALUE*N)
+    }
+
+    fn eig_with(&self, tolerance: Self::Tolerance, max_iterations: usize) -> Option<Self::Output>
+    {
+        let mut t = *self;
+        let mut q = Self::identity();
+        if !schur_in_place(&mut t, &mut q, tolerance, max_iterations)
+        {
+            return None
+        }
+        Some(array_init::array_init(|i| t[i][i]))
+    }
+}
+
+impl<const N: usize> Eig for [[f32; N]; N]
+where
+    Self: SquareMatrix
+{
+    type Output = [Complex<f32>; N];
+    type Tolerance = f32;
+
+    fn eig(&self) -> Option<Self::Output>
+    {
+        self.eig_with(f32::EPSILON, ITERATIONS_PER_EIGENVALUE*N)
+    }
+
+    fn eig_with(&self, tolerance: Self::Tolerance, max_iterations: usize) -> Option<Self::Output>
+    {
+        let mut t = *self;
+        let mut q = Self::identity();
+        if !real_schur_in_place(&mut t, &mut q, tolerance, max_iterations)
+        {
+            return None
+        }
+        Some(real_schur_eigenvalues(&t))
+    }
 }
 
+impl<const N: usize> Eig for [[f64; N]; N]
+where
+    Self: SquareMatrix
+{
+    type Output = [Complex<f64>; N];
+    type Tolerance = f64;
+
+    fn eig(&self) -> Option<Self::Output>
+    {
+        self.eig_with(f64::EPSILON, ITERATIONS_PER_EIGENVALUE*N)
+    }
+
+    fn eig_with(&self, tolerance: Self::Tolerance, max_iterations: usize) -> Option<Self::Output>
+    {
+        let mut t = *self;
+        let mut q = Self::identity();
+        if !real_schur_in_place(&mut t, &mut q, tolerance, max_iterations)
+        {
+            return None
+        }
+        Some(real_schur_eigenvalues(&t))
+    }
+}
+
+pub trait EigVectors: Matrix
+{
+    type OutputValues;
+    type OutputVectors;
+
+    /// Returns the eigenvalues of a matrix together with the matrix of eigenvectors, if the QR-iteration converges
+    /// 
+    /// AV = VΛ
+    /// 
+    /// Each column of V is normalized and belongs to the eigenvalue at the same index.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [2.0, 0.0],
+    ///     [0.0, 3.0]
+    /// ];
+    /// let (lambda, v) = a.eig_vectors().unwrap();
+    /// ```
+    fn eig_vectors(&self) -> Option<(Self::OutputValues, Self::OutputVectors)>;
+
+    /// Returns the eigenvalues and eigenvectors of a matrix, or an error if the QR-iteration does not converge
+    /// 
+    /// AV = VΛ
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a = [
+    ///     [2.0, 0.0],
+    ///     [0.0, 3.0]
+    /// ];
+    /// let (lambda, v) = a.try_eig_vectors()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_eig_vectors(&self) -> Result<(Self::OutputValues, Self::OutputVectors), LinalgError>
+    {
+        self.eig_vectors().ok_or(LinalgError::NoConvergence)
+    }
+}
 
-impl<F: Float, const L: usize, const H: usize> Eig for [[Complex<F>; L]; H]
+impl<F: Float + FloatConst, const N: usize> EigVectors for [[Complex<F>; N]; N]
 where
-    [[Complex<F>; L]; H]: QRHouseholder,
-    <[[Complex<F>; L]; H] as QRHouseholder>::OutputR: Diag
-        + MMul<<[[Complex<F>; L]; H] as QRHouseholder>::OutputQ, Output = [[Complex<F>; L]; H]>
+    Self: SquareMatrix
 {
-    type Output = <<[[Complex<F>; L]; H] as QRHouseholder>::OutputR as Diag>::Output;
-    fn eig(&self) -> Self::Output
+    type OutputValues = [Complex<F>; N];
+    type OutputVectors = [[Complex<F>; N]; N];
+
+    fn eig_vectors(&self) -> Option<(Self::OutputValues, Self::OutputVectors)>
     {
-        let mut a = self.clone();
-        for _ in 0..ITERATIONS
+        let mut t = *self;
+        let mut q = Self::identity();
+        if !schur_in_place(&mut t, &mut q, F::epsilon(), ITERATIONS_PER_EIGENVALUE*N)
+        {
+            return None
+        }
+        let lambda: [Complex<F>; N] = array_init::array_init(|i| t[i][i]);
+
+        // Eigenvectors of T by back substitution, nudging singular pivots of repeated eigenvalues
+        let small = t.iter()
+            .flatten()
+            .map(|tn| tn.norm())
+            .fold(F::zero(), |a, b| a + b)*F::epsilon();
+        let mut v = [[Complex::zero(); N]; N];
+        for k in 0..N
         {
-            let (q, r) = a.qr_householder();
-            a = r.mul(q)
+            let mut y = [Complex::zero(); N];
+            y[k] = Complex::one();
+            for i in (0..k).rev()
+            {
+                let d = t[i][i] - lambda[k];
+                let d = if d.norm() < small {Complex::from(small)} else {d};
+                y[i] = -(i + 1..=k)
+                    .map(|j| t[i][j]*y[j])
+                    .fold(Complex::<F>::zero(), |a, b| a + b)/d;
+            }
+            let x: [Complex<F>; N] = array_init::array_init(|r| (0..=k)
+                .map(|j| q[r][j]*y[j])
+                .fold(Complex::zero(), |a, b| a + b)
+            );
+            let x_abs = norm(&x);
+            for (vr, xr) in v.iter_mut().zip(x)
+            {
+                vr[k] = xr/x_abs;
+            }
         }
-        let r = a.qr_householder().1;
-        r.diag()
+        Some((lambda, v))
     }
 }
 
-impl<const L: usize, const H: usize> Eig for [[f32; L]; H]
+impl<const N: usize> EigVectors for [[f32; N]; N]
 where
     Self: Matrix,
-    [[Complex<f32>; L]; H]: Eig
+    [[Complex<f32>; N]; N]: EigVectors
 {
-    type Output = <[[Complex<f32>; L]; H] as Eig>::Output;
-    fn eig(&self) -> Self::Output
+    type OutputValues = <[[Complex<f32>; N]; N] as EigVectors>::OutputValues;
+    type OutputVectors = <[[Complex<f32>; N]; N] as EigVectors>::OutputVectors;
+    fn eig_vectors(&self) -> Option<(Self::OutputValues, Self::OutputVectors)>
     {
-        self.map(|ar| ar.map(|arc| Complex::from(arc))).eig()
+        self.map(|ar| ar.map(Complex::from)).eig_vectors()
     }
 }
 
-impl<const L: usize, const H: usize> Eig for [[f64; L]; H]
+impl<const N: usize> EigVectors for [[f64; N]; N]
 where
     Self: Matrix,
-    [[Complex<f64>; L]; H]: Eig
+    [[Complex<f64>; N]; N]: EigVectors
 {
-    type Output = <[[Complex<f64>; L]; H] as Eig>::Output;
-    fn eig(&self) -> Self::Output
+    type OutputValues = <[[Complex<f64>; N]; N] as EigVectors>::OutputValues;
+    type OutputVectors = <[[Complex<f64>; N]; N] as EigVectors>::OutputVectors;
+    fn eig_vectors(&self) -> Option<(Self::OutputValues, Self::OutputVectors)>
     {
-        self.map(|ar| ar.map(|arc| Complex::from(arc))).eig()
+        self.map(|ar| ar.map(Complex::from)).eig_vectors()
     }
 }
\ No newline at end of file
@@ -0,0 +1,86 @@
+use num_complex::ComplexFloat;
+use num_traits::{Float, ToPrimitive, Zero, One};
+
+use crate::{SquareMatrix, MMul, MAdd, lu::lu_in_place, triangular::{forward_substitute, back_substitute}};
+
+/// Degree of the diagonal Padé approximant, accurate to machine precision for 1-norms up to ½
+const PADE_DEGREE: usize = 6;
+
+pub trait Expm: SquareMatrix
+{
+    type Output;
+
+    /// Returns the matrix exponential of the given matrix
+    /// 
+    /// eᴬ = I + A + A²/2! + A³/3! + ...
+    /// 
+    /// Computed by scaling and squaring with a diagonal Padé approximant of degree 6.
+    /// If the matrix has infinite or NaN elements, or a 1-norm too large to represent, every element of the result is NaN.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [0.0, 0.0],
+    ///     [0.0, 0.0]
+    /// ];
+    /// assert_eq!(a.expm(), [[1.0, 0.0], [0.0, 1.0]]);
+    /// ```
+    fn expm(&self) -> Self::Output;
+}
+
+impl<F: ComplexFloat, const N: usize> Expm for [[F; N]; N]
+where
+    Self: SquareMatrix
+{
+    type Output = Self;
+
+    fn expm(&self) -> Self::Output
+    {
+        // Scale A by 2⁻ʲ so that its 1-norm is below ½
+        let norm = (0..N)
+            .map(|c| (0..N)
+                .map(|r| self[r][c].abs())
+                .fold(F::Real::zero(), |a, b| a + b)
+            ).fold(F::Real::zero(), Float::max);
+        // The norm alone misses NaN, since max ignores it
+        if !Float::is_finite(norm) || !self.iter().flatten().all(|arc| arc.is_finite())
+        {
+            return [[F::from(F::Real::nan()).unwrap(); N]; N]
+        }
+        let j = if norm > Float::recip(F::Real::one() + F::Real::one())
+        {
+            (Float::log2(norm).floor().to_i32().unwrap() + 2) as usize
+        }
+        else
+        {
+            0
+        };
+        let a = self.mul(F::from(Float::powi(F::Real::one() + F::Real::one(), -(j as i32))).unwrap());
+
+        // Padé approximant D⁻¹N of eᴬ
+        let mut c = F::one();
+        let mut x = Self::identity();
+        let mut n = Self::identity();
+        let mut d = Self::identity();
+        for k in 1..=PADE_DEGREE
+        {
+            c = c*F::from(PADE_DEGREE - k + 1).unwrap()/F::from((2*PADE_DEGREE - k + 1)*k).unwrap();
+            x = a.mul(x);
+            let cx = x.mul(c);
+            n = n.add(cx);
+            d = if k % 2 == 0 {d.add(cx)} else {d.add(cx.mul(-F::one()))};
+        }
+        let perm = lu_in_place(&mut d);
+        let mut e: Self = array_init::array_init(|r| n[perm[r]]);
+        forward_substitute(|r, c| d[r][c], &mut e, true);
+        back_substitute(|r, c| d[r][c], &mut e, false);
+
+        // Undo the scaling by squaring
+        for _ in 0..j
+        {
+            e = e.mul(e);
+        }
+        e
+    }
+}
\ No newline at end of file
@@ -0,0 +1,195 @@
+use num_complex::{Complex, ComplexFloat};
+use num_traits::{Float, FloatConst, Zero};
+
+use crate::{SquareMatrix, Schur, LinalgError};
+
+pub trait Funm: SquareMatrix
+{
+    type Scalar;
+    type Output;
+
+    /// Returns the matrix function of the given matrix, if its Schur-decomposition converges and its eigenvalues are distinct
+    /// 
+    /// f(A)
+    /// 
+    /// Computed by the Parlett recurrence on the complex Schur form, applying `f` to the eigenvalues.
+    /// Returns none if two eigenvalues are closer than √ε·‖A‖, unless the matrix decouples them.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `f` - A complex function, analytic on the eigenvalues of the matrix
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, 2.0],
+    ///     [0.0, 3.0]
+    /// ];
+    /// // Cube root, on the principal branch
+    /// let a_cbrt = a.funm(|z| z.powf(1.0/3.0)).unwrap();
+    /// ```
+    fn funm<Func>(&self, f: Func) -> Option<Self::Output>
+    where
+        Func: Fn(Self::Scalar) -> Self::Scalar
+    {
+        self.try_funm(f).ok()
+    }
+
+    /// Returns the matrix function of the given matrix, or an error telling why it could not be computed
+    /// 
+    /// f(A)
+    /// 
+    /// # Arguments
+    /// 
+    /// * `f` - A complex function, analytic on the eigenvalues of the matrix
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a: [[f64; 2]; 2] = [
+    ///     [1.0, 1.0],
+    ///     [0.0, 1.0]
+    /// ];
+    /// assert_eq!(a.try_funm(|z| z.exp()), Err(LinalgError::RepeatedEigenvalues));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_funm<Func>(&self, f: Func) -> Result<Self::Output, LinalgError>
+    where
+        Func: Fn(Self::Scalar) -> Self::Scalar;
+}
+
+impl<F: Float + FloatConst, const N: usize> Funm for [[Complex<F>; N]; N]
+where
+    Self: Schur<OutputQ = Self, OutputT = Self>
+{
+    type Scalar = Complex<F>;
+    type Output = Self;
+
+    fn try_funm<Func>(&self, f: Func) -> Result<Self::Output, LinalgError>
+    where
+        Func: Fn(Self::Scalar) -> Self::Scalar
+    {
+        let (q, t) = self.try_schur()?;
+        let ft = parlett(&t, f).ok_or(LinalgError::RepeatedEigenvalues)?;
+        Ok(unitary_similarity(&q, &ft))
+    }
+}
+
+impl<const N: usize> Funm for [[f32; N]; N]
+where
+    Self: SquareMatrix,
+    [[Complex<f32>; N]; N]: Funm<Scalar = Complex<f32>>
+{
+    type Scalar = Complex<f32>;
+    type Output = <[[Complex<f32>; N]; N] as Funm>::Output;
+
+    fn try_funm<Func>(&self, f: Func) -> Result<Self::Output, LinalgError>
+    where
+        Func: Fn(Self::Scalar) -> Self::Scalar
+    {
+        self.map(|ar| ar.map(Complex::from)).try_funm(f)
+    }
+}
+
+impl<const N: usize> Funm for [[f64; N]; N]
+where
+    Self: SquareMatrix,
+    [[Complex<f64>; N]; N]: Funm<Scalar = Complex<f64>>
+{
+    type Scalar = Complex<f64>;
+    type Output = <[[Complex<f64>; N]; N] as Funm>::Output;
+
+    fn try_funm<Func>(&self, f: Func) -> Result<Self::Output, LinalgError>
+    where
+        Func: Fn(Self::Scalar) -> Self::Scalar
+    {
+        self.map(|ar| ar.map(Complex::from)).try_funm(f)
+    }
+}
+
+/// Evaluates f(T) for an upper-triangular T by the Parlett recurrence.
+/// 
+/// Returns none if two diagonal elements are closer than √ε·‖T‖, and the elements coupling them are not all zero.
+/// The recurrence divides by their difference, so closer eigenvalues would lose most of the precision.
+pub(crate) fn parlett<F: ComplexFloat, const N: usize>(t: &[[F; N]; N], f: impl Fn(F) -> F) -> Option<[[F; N]; N]>
+{
+    let t_abs = Float::sqrt(t.iter()
+        .flatten()
+        .map(|tn| tn.abs()*tn.abs())
+        .fold(Zero::zero(), |a: F::Real, b| a + b));
+    let tolerance = Float::sqrt(<F::Real as Float>::epsilon())*t_abs;
+    let mut ft = [[F::zero(); N]; N];
+    for i in 0..N
+    {
+        ft[i][i] = f(t[i][i]);
+    }
+    // Superdiagonals one at a time, since each element depends on the ones to its left and below
+    for d in 1..N
+    {
+        for i in 0..N - d
+        {
+            let j = i + d;
+            let coupling = (i + 1..j)
+                .map(|k| t[i][k]*ft[k][j] - ft[i][k]*t[k][j])
+                .fold(F::zero(), |a, b| a + b);
+            let dt = t[j][j] - t[i][i];
+            ft[i][j] = if dt.abs() <= tolerance || dt.is_zero()
+            {
+                if !t[i][j].is_zero() || !coupling.is_zero()
+                {
+                    return None
+                }
+                F::zero()
+            }
+            else
+            {
+                (t[i][j]*(ft[j][j] - ft[i][i]) + coupling)/dt
+            };
+        }
+    }
+    Some(ft)
+}
+
+/// Returns QTQᴴ.
+pub(crate) fn unitary_similarity<F: ComplexFloat, const N: usize>(q: &[[F; N]; N], t: &[[F; N]; N]) -> [[F; N]; N]
+{
+    let qt: [[F; N]; N] = array_init::array_init(|r| array_init::array_init(|c| (0..N)
+        .map(|k| q[r][k]*t[k][c])
+        .fold(F::zero(), |a, b| a + b)
+    ));
+    array_init::array_init(|r| array_init::array_init(|c| (0..N)
+        .map(|k| qt[r][k]*q[c][k].conj())
+        .fold(Zero::zero(), |a: F, b| a + b)
+    ))
+}
+
+/// Returns true if the diagonal element of the triangular matrix T lies on the negative real axis, allowing for
+/// rounding errors in its imaginary part relative to N·ε·‖T‖.
+pub(crate) fn on_negative_real_axis<F: Float, const N: usize>(t: &[[Complex<F>; N]; N], i: usize, include_zero: bool) -> bool
+{
+    let t_abs = Float::sqrt(t.iter()
+        .flatten()
+        .map(|tn| tn.norm_sqr())
+        .fold(F::zero(), |a, b| a + b));
+    let tolerance = F::epsilon()*F::from(N).unwrap()*t_abs;
+    let z = t[i][i];
+    z.im.abs() <= tolerance && (z.re < F::zero() || include_zero && z.re <= F::zero())
+}
+
+/// Returns the real part of a matrix that should be real, or none if its imaginary part is more than rounding errors.
+pub(crate) fn real_part<F: Float, const N: usize>(x: [[Complex<F>; N]; N]) -> Option<[[F; N]; N]>
+{
+    let (re_sqr, im_sqr) = x.iter()
+        .flatten()
+        .fold((F::zero(), F::zero()), |(re, im), xn| (re + xn.re*xn.re, im + xn.im*xn.im));
+    if Float::sqrt(im_sqr) > F::epsilon().sqrt()*Float::sqrt(re_sqr + im_sqr)
+    {
+        return None
+    }
+    Some(x.map(|xr| xr.map(|xrc| xrc.re)))
+}
\ No newline at end of file
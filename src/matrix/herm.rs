@@ -39,4 +39,28 @@ where
     {
         matrix_init(|r, c| self[c][r].conj())
     }
+}
+
+impl<const L: usize, const H: usize> Herm for [[f32; L]; H]
+where
+    Self: Matrix,
+    [[f32; H]; L]: Matrix
+{
+    type Output = [[f32; H]; L];
+    fn herm(&self) -> Self::Output
+    {
+        matrix_init(|r, c| self[c][r])
+    }
+}
+
+impl<const L: usize, const H: usize> Herm for [[f64; L]; H]
+where
+    Self: Matrix,
+    [[f64; H]; L]: Matrix
+{
+    type Output = [[f64; H]; L];
+    fn herm(&self) -> Self::Output
+    {
+        matrix_init(|r, c| self[c][r])
+    }
 }
\ No newline at end of file
@@ -0,0 +1,106 @@
+use num_complex::ComplexFloat;
+use num_traits::{Float, Zero};
+
+use crate::SquareMatrix;
+
+pub trait Hessenberg: SquareMatrix
+{
+    type OutputQ;
+    type OutputH;
+
+    /// Returns the Hessenberg-decomposition of the given matrix using Householder reflections
+    /// 
+    /// A = QHQᴴ
+    /// 
+    /// Q is unitary and H is upper Hessenberg, i.e. zero below the first subdiagonal.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, 2.0, 3.0],
+    ///     [4.0, 5.0, 6.0],
+    ///     [7.0, 8.0, 10.0]
+    /// ];
+    /// let (q, h) = a.hessenberg();
+    /// assert_eq!(h[2][0], 0.0);
+    /// ```
+    fn hessenberg(&self) -> (Self::OutputQ, Self::OutputH);
+}
+
+impl<F: ComplexFloat, const N: usize> Hessenberg for [[F; N]; N]
+where
+    Self: SquareMatrix
+{
+    type OutputQ = Self;
+    type OutputH = Self;
+
+    fn hessenberg(&self) -> (Self::OutputQ, Self::OutputH)
+    {
+        let mut h = *self;
+        let mut q = Self::identity();
+        hessenberg_in_place(&mut h, &mut q);
+        (q, h)
+    }
+}
+
+/// Reduces `h` to upper Hessenberg form in place by Householder reflections, applying the same reflections to the columns of `q`.
+pub(crate) fn hessenberg_in_place<F: ComplexFloat, const N: usize>(h: &mut [[F; N]; N], q: &mut [[F; N]; N])
+{
+    let two = F::one() + F::one();
+    for k in 0..N.saturating_sub(2)
+    {
+        let mut v = [F::zero(); N];
+        for i in k + 1..N
+        {
+            v[i] = h[i][k];
+        }
+        let x_abs = F::from(norm(&v)).unwrap();
+        if x_abs.is_zero()
+        {
+            continue
+        }
+        let phase = if v[k + 1].is_zero() {F::one()} else {v[k + 1]/F::from(v[k + 1].abs()).unwrap()};
+        v[k + 1] = v[k + 1] + phase*x_abs;
+        let v_abs = F::from(norm(&v)).unwrap();
+        for vi in v.iter_mut()
+        {
+            *vi = *vi/v_abs;
+        }
+
+        // H = (I - 2vvᴴ)H(I - 2vvᴴ), Q = Q(I - 2vvᴴ)
+        let w: [F; N] = array_init::array_init(|j| (k + 1..N)
+            .map(|i| v[i].conj()*h[i][j])
+            .fold(F::zero(), |a, b| a + b)
+        );
+        for (hr, &vr) in h.iter_mut().zip(v.iter()).skip(k + 1)
+        {
+            for (hrc, &wc) in hr.iter_mut().zip(w.iter())
+            {
+                *hrc = *hrc - two*vr*wc;
+            }
+        }
+        for hr in h.iter_mut().chain(q.iter_mut())
+        {
+            let s = (k + 1..N)
+                .map(|j| hr[j]*v[j])
+                .fold(F::zero(), |a, b| a + b);
+            for j in k + 1..N
+            {
+                hr[j] = hr[j] - two*s*v[j].conj();
+            }
+        }
+        for hr in h[k + 2..].iter_mut()
+        {
+            hr[k] = F::zero();
+        }
+    }
+}
+
+/// Returns the euclidian norm of a slice.
+pub(crate) fn norm<F: ComplexFloat>(v: &[F]) -> F::Real
+{
+    Float::sqrt(v.iter()
+        .map(|vn| vn.abs()*vn.abs())
+        .fold(Zero::zero(), |a, b| a + b))
+}
\ No newline at end of file
@@ -5,6 +5,7 @@ use crate::MMul;
 use crate::Adj;
 use crate::Det;
 use crate::SquareMatrix;
+use crate::LinalgError;
 
 pub trait MInv: SquareMatrix
 where
@@ -35,6 +36,29 @@ where
     /// assert_eq!(a.inv(), Some(ai));
     /// ```
     fn inv(&self) -> Option<Self::Output>;
+
+    /// Returns the inverted matrix, or an error if the matrix is singular
+    /// 
+    /// A⁻¹
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a = [
+    ///     [1.0, 2.0],
+    ///     [2.0, 4.0]
+    /// ];
+    /// assert_eq!(a.try_inv(), Err(LinalgError::Singular));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_inv(&self) -> Result<Self::Output, LinalgError>
+    {
+        self.inv().ok_or(LinalgError::Singular)
+    }
 }
 
 impl<F, M> MInv for M
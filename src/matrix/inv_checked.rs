@@ -0,0 +1,94 @@
+use num_complex::ComplexFloat;
+use num_traits::Float;
+
+use crate::{SquareMatrix, MNorm, LinalgError, lu::lu_in_place, triangular::{forward_substitute, back_substitute}};
+
+pub trait MInvChecked: SquareMatrix
+{
+    type Output;
+    type Tolerance;
+
+    /// Returns the inverted matrix if the matrix is well-conditioned
+    /// 
+    /// A⁻¹
+    /// 
+    /// The inverse is computed by LU-decomposition with partial pivoting.
+    /// Unlike `inv`, this returns none not only for exactly singular matrices, but also whenever the reciprocal of the condition number
+    /// in the 1-norm, 1/(||A||₁||A⁻¹||₁), is at or below the tolerance, since the result would be numerically meaningless.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `tolerance` - The smallest acceptable reciprocal condition number, typically a small multiple of machine precision
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, 1.0],
+    ///     [1.0, 1.0 + 1e-14]
+    /// ];
+    /// assert_eq!(a.inv_checked(1e-12), None);
+    /// 
+    /// let a = [
+    ///     [2.0, 0.0],
+    ///     [0.0, 4.0]
+    /// ];
+    /// assert_eq!(a.inv_checked(1e-12), Some([[0.5, 0.0], [0.0, 0.25]]));
+    /// ```
+    fn inv_checked(&self, tolerance: Self::Tolerance) -> Option<Self::Output>
+    {
+        self.try_inv_checked(tolerance).ok()
+    }
+
+    /// Returns the inverted matrix, or an error if the matrix is singular or ill-conditioned
+    /// 
+    /// A⁻¹
+    /// 
+    /// # Arguments
+    /// 
+    /// * `tolerance` - The smallest acceptable reciprocal condition number, typically a small multiple of machine precision
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a = [
+    ///     [1.0, 1.0],
+    ///     [1.0, 1.0 + 1e-14]
+    /// ];
+    /// assert_eq!(a.try_inv_checked(1e-12), Err(LinalgError::IllConditioned));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_inv_checked(&self, tolerance: Self::Tolerance) -> Result<Self::Output, LinalgError>;
+}
+
+impl<F: ComplexFloat, const N: usize> MInvChecked for [[F; N]; N]
+where
+    Self: SquareMatrix + MNorm<Output = F::Real>
+{
+    type Output = Self;
+    type Tolerance = F::Real;
+
+    fn try_inv_checked(&self, tolerance: Self::Tolerance) -> Result<Self::Output, LinalgError>
+    {
+        let mut lu = *self;
+        let perm = lu_in_place(&mut lu);
+        if (0..N).any(|i| lu[i][i].is_zero())
+        {
+            return Err(LinalgError::Singular)
+        }
+        let mut ai: Self = array_init::array_init(|r| array_init::array_init(|c| if perm[r] == c {F::one()} else {F::zero()}));
+        forward_substitute(|r, c| lu[r][c], &mut ai, true);
+        back_substitute(|r, c| lu[r][c], &mut ai, false);
+
+        let rcond = Float::recip(self.norm_1()*ai.norm_1());
+        if Float::is_nan(rcond) || rcond <= tolerance
+        {
+            return Err(LinalgError::IllConditioned)
+        }
+        Ok(ai)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,128 @@
+use num_complex::Complex;
+use num_traits::{Float, FloatConst, Zero};
+
+use crate::{Matrix, QRHouseholder, LinalgError, hessenberg::norm, triangular::back_substitute};
+
+pub trait LeastSquares<Rhs>: Matrix
+{
+    type Output;
+    type Residual;
+
+    /// Returns the least-squares solution of an overdetermined linear system together with the norm of its residual,
+    /// if the matrix has full collumn rank
+    /// 
+    /// argminₓ ‖Ax - b‖
+    /// 
+    /// The system is solved by Householder QR-decomposition and back-substitution, without forming the normal equations.
+    /// The rank is considered deficient if a diagonal element of R is below max(H, L)·ε times the largest one.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rhs` - A vector with length equal this matrix's height
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// // Fit a line through three points
+    /// let a = [
+    ///     [1.0, 0.0],
+    ///     [1.0, 1.0],
+    ///     [1.0, 2.0]
+    /// ];
+    /// let b = [1.0, 3.0, 5.0];
+    /// let (x, residual) = a.least_squares(b).unwrap();
+    /// ```
+    fn least_squares(&self, rhs: Rhs) -> Option<(Self::Output, Self::Residual)>;
+
+    /// Returns the least-squares solution and the norm of its residual, or an error if the matrix is wider than it is tall or does not have full column rank
+    /// 
+    /// argminₓ ||Ax - b||
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a = [
+    ///     [1.0, 2.0],
+    ///     [2.0, 4.0],
+    ///     [3.0, 6.0]
+    /// ];
+    /// let b = [1.0, 2.0, 3.0];
+    /// assert_eq!(a.try_least_squares(b), Err(LinalgError::RankDeficient));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_least_squares(&self, rhs: Rhs) -> Result<(Self::Output, Self::Residual), LinalgError>
+    {
+        if self.height() < self.length()
+        {
+            return Err(LinalgError::DimensionMismatch)
+        }
+        self.least_squares(rhs).ok_or(LinalgError::RankDeficient)
+    }
+}
+
+impl<F: Float + FloatConst, const L: usize, const H: usize> LeastSquares<[Complex<F>; H]> for [[Complex<F>; L]; H]
+where
+    Self: QRHouseholder<OutputQ = [[Complex<F>; H]; H], OutputR = [[Complex<F>; L]; H]>
+{
+    type Output = [Complex<F>; L];
+    type Residual = F;
+
+    fn least_squares(&self, rhs: [Complex<F>; H]) -> Option<(Self::Output, Self::Residual)>
+    {
+        // A wide matrix can never have full column rank
+        if H < L
+        {
+            return None
+        }
+        let (q, r) = self.qr_householder();
+        let qhb: [Complex<F>; H] = array_init::array_init(|c| (0..H)
+            .map(|i| q[i][c].conj()*rhs[i])
+            .fold(Complex::zero(), |a, b| a + b)
+        );
+        let r: [[Complex<F>; L]; L] = array_init::array_init(|i| r[i]);
+        // Diagonal elements of R negligible next to the largest one mean that the columns are numerically dependent
+        let r_max = (0..L).map(|i| r[i][i].norm()).fold(F::zero(), F::max);
+        let tolerance = r_max*F::epsilon()*F::from(H.max(L)).unwrap();
+        if (0..L).any(|i| r[i][i].norm() <= tolerance)
+        {
+            return None
+        }
+        let mut x: [[Complex<F>; 1]; L] = array_init::array_init(|i| [qhb[i]]);
+        back_substitute(|i, j| r[i][j], &mut x, false);
+        Some((x.map(|[x]| x), norm(&qhb[L..])))
+    }
+}
+
+impl<const L: usize, const H: usize> LeastSquares<[f32; H]> for [[f32; L]; H]
+where
+    Self: Matrix,
+    [[Complex<f32>; L]; H]: LeastSquares<[Complex<f32>; H], Output = [Complex<f32>; L], Residual = f32>
+{
+    type Output = [f32; L];
+    type Residual = f32;
+    fn least_squares(&self, rhs: [f32; H]) -> Option<(Self::Output, Self::Residual)>
+    {
+        self.map(|ar| ar.map(Complex::from))
+            .least_squares(rhs.map(Complex::from))
+            .map(|(x, residual)| (x.map(|xn| xn.re), residual))
+    }
+}
+
+impl<const L: usize, const H: usize> LeastSquares<[f64; H]> for [[f64; L]; H]
+where
+    Self: Matrix,
+    [[Complex<f64>; L]; H]: LeastSquares<[Complex<f64>; H], Output = [Complex<f64>; L], Residual = f64>
+{
+    type Output = [f64; L];
+    type Residual = f64;
+    fn least_squares(&self, rhs: [f64; H]) -> Option<(Self::Output, Self::Residual)>
+    {
+        self.map(|ar| ar.map(Complex::from))
+            .least_squares(rhs.map(Complex::from))
+            .map(|(x, residual)| (x.map(|xn| xn.re), residual))
+    }
+}
\ No newline at end of file
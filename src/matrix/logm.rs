@@ -0,0 +1,156 @@
+use num_complex::Complex;
+use num_traits::{Float, FloatConst};
+
+use crate::{SquareMatrix, Schur, LinalgError, funm::{unitary_similarity, on_negative_real_axis, real_part}, sqrtm::sqrtm_triangular, triangular::back_substitute};
+
+/// Upper limit of the 1-norm of T - I for the quadrature to reach machine precision
+const LOG_RADIUS: f64 = 0.25;
+/// Limit on the number of square roots taken before giving up
+const MAX_SQUARE_ROOTS: usize = 64;
+/// Gauss-Legendre nodes and weights on [0, 1]
+const GAUSS_LEGENDRE: [(f64, f64); 8] = [
+    (0.019855071751231856, 0.05061426814518809),
+    (0.10166676129318658, 0.11119051722668723),
+    (0.2372337950418355, 0.1568533229389437),
+    (0.4082826787521751, 0.181341891689181),
+    (0.591717321247825, 0.181341891689181),
+    (0.7627662049581645, 0.1568533229389437),
+    (0.8983332387068135, 0.11119051722668723),
+    (0.9801449282487682, 0.05061426814518809)
+];
+
+pub trait Logm: SquareMatrix
+{
+    type Output;
+
+    /// Returns the principal logarithm of the given matrix, if it exists and the Schur-decomposition converges
+    /// 
+    /// log(A)
+    /// 
+    /// The principal logarithm is the one whose eigenvalues have imaginary parts in (-π, π).
+    /// Returns none if the matrix has eigenvalues on the closed negative real axis, since there it's either undefined or not unique.
+    /// 
+    /// Computed by inverse scaling and squaring, taking square roots of the Schur form until it's close to the identity matrix.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, 0.0],
+    ///     [0.0, 1.0]
+    /// ];
+    /// assert_eq!(a.logm(), Some([[0.0, 0.0], [0.0, 0.0]]));
+    /// ```
+    fn logm(&self) -> Option<Self::Output>
+    {
+        self.try_logm().ok()
+    }
+
+    /// Returns the principal logarithm of the given matrix, or an error telling why it could not be computed
+    /// 
+    /// log(A)
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a = [
+    ///     [0.0, 0.0],
+    ///     [0.0, 1.0]
+    /// ];
+    /// assert_eq!(a.try_logm(), Err(LinalgError::EigenvalueOnNegativeRealAxis));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_logm(&self) -> Result<Self::Output, LinalgError>;
+}
+
+impl<F: Float + FloatConst, const N: usize> Logm for [[Complex<F>; N]; N]
+where
+    Self: Schur<OutputQ = Self, OutputT = Self>
+{
+    type Output = Self;
+
+    fn try_logm(&self) -> Result<Self::Output, LinalgError>
+    {
+        let (q, mut t) = self.try_schur()?;
+        if (0..N).any(|i| on_negative_real_axis(&t, i, true))
+        {
+            return Err(LinalgError::EigenvalueOnNegativeRealAxis)
+        }
+
+        // T^(1/2ᵏ) is close to I
+        let radius = F::from(LOG_RADIUS).unwrap();
+        let mut k = 0;
+        loop
+        {
+            let dist = (0..N)
+                .map(|c| (0..=c)
+                    .map(|r| (t[r][c] - if r == c {Complex::from(F::one())} else {Complex::from(F::zero())}).norm())
+                    .fold(F::zero(), |a, b| a + b)
+                ).fold(F::zero(), Float::max);
+            if dist <= radius
+            {
+                break
+            }
+            if k >= MAX_SQUARE_ROOTS
+            {
+                return Err(LinalgError::NoConvergence)
+            }
+            t = sqrtm_triangular(&t).ok_or(LinalgError::Singular)?;
+            k += 1;
+        }
+
+        // log(I + X) = ∫₀¹ X(I + sX)⁻¹ ds
+        let x: Self = array_init::array_init(|r| array_init::array_init(|c| if r == c {t[r][c] - F::one()} else {t[r][c]}));
+        let mut l = [[Complex::from(F::zero()); N]; N];
+        for (s, w) in GAUSS_LEGENDRE
+        {
+            let (s, w) = (F::from(s).unwrap(), F::from(w).unwrap());
+            let mut y = x;
+            back_substitute(|r, c| if r == c {x[r][c]*s + F::one()} else {x[r][c]*s}, &mut y, false);
+            for (lr, yr) in l.iter_mut().zip(y)
+            {
+                for (lrc, yrc) in lr.iter_mut().zip(yr)
+                {
+                    *lrc = *lrc + yrc*w;
+                }
+            }
+        }
+        let scale = F::from(2.0).unwrap().powi(k as i32);
+        let l = l.map(|lr| lr.map(|lrc| lrc*scale));
+        Ok(unitary_similarity(&q, &l))
+    }
+}
+
+impl<const N: usize> Logm for [[f32; N]; N]
+where
+    Self: SquareMatrix,
+    [[Complex<f32>; N]; N]: Logm<Output = [[Complex<f32>; N]; N]>
+{
+    type Output = Self;
+
+    fn try_logm(&self) -> Result<Self::Output, LinalgError>
+    {
+        self.map(|ar| ar.map(Complex::from))
+            .try_logm()
+            .and_then(|x| real_part(x).ok_or(LinalgError::EigenvalueOnNegativeRealAxis))
+    }
+}
+
+impl<const N: usize> Logm for [[f64; N]; N]
+where
+    Self: SquareMatrix,
+    [[Complex<f64>; N]; N]: Logm<Output = [[Complex<f64>; N]; N]>
+{
+    type Output = Self;
+
+    fn try_logm(&self) -> Result<Self::Output, LinalgError>
+    {
+        self.map(|ar| ar.map(Complex::from))
+            .try_logm()
+            .and_then(|x| real_part(x).ok_or(LinalgError::EigenvalueOnNegativeRealAxis))
+    }
+}
\ No newline at end of file
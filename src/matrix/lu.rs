@@ -0,0 +1,103 @@
+use num_complex::ComplexFloat;
+
+use crate::{matrix_init, SquareMatrix};
+
+pub trait LU: SquareMatrix
+{
+    type OutputP;
+    type OutputL;
+    type OutputU;
+
+    /// Returns the LU-decomposition of the given matrix using partial pivoting
+    /// 
+    /// PA = LU
+    /// 
+    /// P is a permutation matrix, L is unit lower-triangular and U is upper-triangular.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0]
+    /// ];
+    /// let (p, l, u) = a.lu();
+    /// assert_eq!(p.mul(a), l.mul(u));
+    /// ```
+    fn lu(&self) -> (Self::OutputP, Self::OutputL, Self::OutputU);
+}
+
+impl<F: ComplexFloat, const N: usize> LU for [[F; N]; N]
+where
+    Self: SquareMatrix
+{
+    type OutputP = Self;
+    type OutputL = Self;
+    type OutputU = Self;
+
+    fn lu(&self) -> (Self::OutputP, Self::OutputL, Self::OutputU)
+    {
+        let mut a = *self;
+        let perm = lu_in_place(&mut a);
+
+        let p = matrix_init(|r, c| if perm[r] == c {F::one()} else {F::zero()});
+        let l = matrix_init(|r, c| if r == c {F::one()} else if r > c {a[r][c]} else {F::zero()});
+        let u = matrix_init(|r, c| if r <= c {a[r][c]} else {F::zero()});
+        (p, l, u)
+    }
+}
+
+/// Performs Gaussian elimination with partial pivoting in place.
+/// 
+/// Afterwards the strictly lower part of `a` holds the multipliers of L, and the upper part holds U.
+/// Returns the row permutation, such that row `r` of PA is row `perm[r]` of A.
+pub(crate) fn lu_in_place<F: ComplexFloat, const N: usize>(a: &mut [[F; N]; N]) -> [usize; N]
+{
+    let mut perm: [usize; N] = array_init::array_init(|i| i);
+    for k in 0..N
+    {
+        let p = (k..N)
+            .reduce(|i, j| if a[j][k].abs() > a[i][k].abs() {j} else {i})
+            .unwrap();
+        if p != k
+        {
+            a.swap(p, k);
+            perm.swap(p, k);
+        }
+        if a[k][k].is_zero()
+        {
+            continue
+        }
+        let pivot = a[k];
+        for ar in a[k + 1..].iter_mut()
+        {
+            let m = ar[k]/pivot[k];
+            ar[k] = m;
+            for (arc, &pc) in ar.iter_mut().zip(pivot.iter()).skip(k + 1)
+            {
+                *arc = *arc - m*pc;
+            }
+        }
+    }
+    perm
+}
+
+/// Returns the determinant from the LU-decomposition and row permutation given by `lu_in_place`.
+pub(crate) fn lu_det<F: ComplexFloat, const N: usize>(lu: &[[F; N]; N], perm: &[usize; N]) -> F
+{
+    let mut perm = *perm;
+    let mut odd = false;
+    for i in 0..N
+    {
+        while perm[i] != i
+        {
+            let j = perm[i];
+            perm.swap(i, j);
+            odd = !odd;
+        }
+    }
+    let d = (0..N)
+        .map(|i| lu[i][i])
+        .fold(F::one(), |a, b| a*b);
+    if odd {-d} else {d}
+}
\ No newline at end of file
@@ -0,0 +1,298 @@
+use std::ops::{Add, AddAssign, Deref, DerefMut, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num_traits::Zero;
+
+use crate::Column;
+
+/// A matrix with `H` rows and `L` columns, wrapping the array form `[[T; L]; H]` to provide operator overloading
+/// 
+/// The wrapper has the same layout as the array, and dereferences to it, so all the matrix traits are still available.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// let a = Mat([
+///     [1.0, 2.0],
+///     [3.0, 4.0]
+/// ]);
+/// let x = [1.0, 1.0];
+/// assert_eq!(a*x, [3.0, 7.0]);
+/// assert_eq!((a + a)[(1, 0)], 6.0);
+/// assert_eq!(a.det(), -2.0);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Mat<T, const H: usize, const L: usize>(pub [[T; L]; H]);
+
+impl<T, const H: usize, const L: usize> Mat<T, H, L>
+{
+    /// Returns the wrapped array
+    pub fn into_array(self) -> [[T; L]; H]
+    {
+        self.0
+    }
+
+    /// Reinterprets a reference to an array as a reference to a matrix
+    pub fn from_ref(array: &[[T; L]; H]) -> &Self
+    {
+        // SAFETY: Mat is a transparent wrapper around the array
+        unsafe {&*(array as *const [[T; L]; H] as *const Self)}
+    }
+
+    /// Reinterprets a mutable reference to an array as a mutable reference to a matrix
+    pub fn from_mut(array: &mut [[T; L]; H]) -> &mut Self
+    {
+        // SAFETY: Mat is a transparent wrapper around the array
+        unsafe {&mut *(array as *mut [[T; L]; H] as *mut Self)}
+    }
+}
+
+impl<T, const H: usize, const L: usize> From<[[T; L]; H]> for Mat<T, H, L>
+{
+    fn from(array: [[T; L]; H]) -> Self
+    {
+        Self(array)
+    }
+}
+
+impl<T, const H: usize, const L: usize> From<Mat<T, H, L>> for [[T; L]; H]
+{
+    fn from(mat: Mat<T, H, L>) -> Self
+    {
+        mat.0
+    }
+}
+
+impl<T, const H: usize, const L: usize> AsRef<[[T; L]; H]> for Mat<T, H, L>
+{
+    fn as_ref(&self) -> &[[T; L]; H]
+    {
+        &self.0
+    }
+}
+
+impl<T, const H: usize, const L: usize> AsMut<[[T; L]; H]> for Mat<T, H, L>
+{
+    fn as_mut(&mut self) -> &mut [[T; L]; H]
+    {
+        &mut self.0
+    }
+}
+
+impl<T, const H: usize, const L: usize> Deref for Mat<T, H, L>
+{
+    type Target = [[T; L]; H];
+
+    fn deref(&self) -> &Self::Target
+    {
+        &self.0
+    }
+}
+
+impl<T, const H: usize, const L: usize> DerefMut for Mat<T, H, L>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target
+    {
+        &mut self.0
+    }
+}
+
+impl<T, const H: usize, const L: usize> Index<(usize, usize)> for Mat<T, H, L>
+{
+    type Output = T;
+
+    fn index(&self, (r, c): (usize, usize)) -> &Self::Output
+    {
+        &self.0[r][c]
+    }
+}
+
+impl<T, const H: usize, const L: usize> IndexMut<(usize, usize)> for Mat<T, H, L>
+{
+    fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut Self::Output
+    {
+        &mut self.0[r][c]
+    }
+}
+
+impl<T1, T2, const H: usize, const L: usize> Add<Mat<T2, H, L>> for Mat<T1, H, L>
+where
+    T1: Add<T2>
+{
+    type Output = Mat<<T1 as Add<T2>>::Output, H, L>;
+
+    fn add(self, rhs: Mat<T2, H, L>) -> Self::Output
+    {
+        Mat(zip_with(self.0, rhs.0, |a, b| a + b))
+    }
+}
+
+impl<T1, T2, const H: usize, const L: usize> Sub<Mat<T2, H, L>> for Mat<T1, H, L>
+where
+    T1: Sub<T2>
+{
+    type Output = Mat<<T1 as Sub<T2>>::Output, H, L>;
+
+    fn sub(self, rhs: Mat<T2, H, L>) -> Self::Output
+    {
+        Mat(zip_with(self.0, rhs.0, |a, b| a - b))
+    }
+}
+
+impl<T, const H: usize, const L: usize> Neg for Mat<T, H, L>
+where
+    T: Neg
+{
+    type Output = Mat<<T as Neg>::Output, H, L>;
+
+    fn neg(self) -> Self::Output
+    {
+        Mat(self.0.map(|ar| ar.map(|arc| -arc)))
+    }
+}
+
+impl<T, const H: usize, const L: usize> Mul<T> for Mat<T, H, L>
+where
+    T: Mul<T, Output = T> + Copy
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output
+    {
+        Mat(self.0.map(|ar| ar.map(|arc| arc*rhs)))
+    }
+}
+
+impl<T, const H: usize, const L: usize> Div<T> for Mat<T, H, L>
+where
+    T: Div<T, Output = T> + Copy
+{
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output
+    {
+        Mat(self.0.map(|ar| ar.map(|arc| arc/rhs)))
+    }
+}
+
+impl<T, const H: usize, const K: usize, const L: usize> Mul<Mat<T, K, L>> for Mat<T, H, K>
+where
+    T: Mul<T, Output = T> + Add<T, Output = T> + Zero + Copy
+{
+    type Output = Mat<T, H, L>;
+
+    fn mul(self, rhs: Mat<T, K, L>) -> Self::Output
+    {
+        Mat(self.0.map(|ar| array_init::array_init(|c| ar.iter()
+            .zip(rhs.0.iter())
+            .map(|(&ark, bk)| ark*bk[c])
+            .fold(T::zero(), |a, b| a + b)
+        )))
+    }
+}
+
+impl<T, const H: usize, const L: usize> Mul<[T; L]> for Mat<T, H, L>
+where
+    T: Mul<T, Output = T> + Add<T, Output = T> + Zero + Copy
+{
+    type Output = [T; H];
+
+    fn mul(self, rhs: [T; L]) -> Self::Output
+    {
+        self.0.map(|ar| ar.iter()
+            .zip(rhs.iter())
+            .map(|(&arc, &xc)| arc*xc)
+            .fold(T::zero(), |a, b| a + b)
+        )
+    }
+}
+
+impl<T, const H: usize, const L: usize> Mul<Column<T, L>> for Mat<T, H, L>
+where
+    Self: Mul<[T; L], Output = [T; H]>
+{
+    type Output = Column<T, H>;
+
+    fn mul(self, rhs: Column<T, L>) -> Self::Output
+    {
+        Column(self*rhs.0)
+    }
+}
+
+impl<T1, T2, const H: usize, const L: usize> AddAssign<Mat<T2, H, L>> for Mat<T1, H, L>
+where
+    T1: AddAssign<T2>
+{
+    fn add_assign(&mut self, rhs: Mat<T2, H, L>)
+    {
+        for (ar, br) in self.0.iter_mut().zip(rhs.0)
+        {
+            for (arc, brc) in ar.iter_mut().zip(br)
+            {
+                *arc += brc;
+            }
+        }
+    }
+}
+
+impl<T1, T2, const H: usize, const L: usize> SubAssign<Mat<T2, H, L>> for Mat<T1, H, L>
+where
+    T1: SubAssign<T2>
+{
+    fn sub_assign(&mut self, rhs: Mat<T2, H, L>)
+    {
+        for (ar, br) in self.0.iter_mut().zip(rhs.0)
+        {
+            for (arc, brc) in ar.iter_mut().zip(br)
+            {
+                *arc -= brc;
+            }
+        }
+    }
+}
+
+impl<T, const H: usize, const L: usize> MulAssign<T> for Mat<T, H, L>
+where
+    T: MulAssign<T> + Copy
+{
+    fn mul_assign(&mut self, rhs: T)
+    {
+        for arc in self.0.iter_mut().flatten()
+        {
+            *arc *= rhs;
+        }
+    }
+}
+
+impl<T, const H: usize, const L: usize> DivAssign<T> for Mat<T, H, L>
+where
+    T: DivAssign<T> + Copy
+{
+    fn div_assign(&mut self, rhs: T)
+    {
+        for arc in self.0.iter_mut().flatten()
+        {
+            *arc /= rhs;
+        }
+    }
+}
+
+impl<T, const H: usize, const L: usize> MulAssign<Mat<T, L, L>> for Mat<T, H, L>
+where
+    Self: Mul<Mat<T, L, L>, Output = Self> + Copy
+{
+    fn mul_assign(&mut self, rhs: Mat<T, L, L>)
+    {
+        *self = *self*rhs;
+    }
+}
+
+/// Combines two arrays of the same dimensions element by element.
+fn zip_with<T1, T2, O, const H: usize, const L: usize>(a: [[T1; L]; H], b: [[T2; L]; H], f: impl Fn(T1, T2) -> O) -> [[O; L]; H]
+{
+    let mut b = b.into_iter();
+    a.map(|ar| {
+        let mut br = b.next().unwrap().into_iter();
+        ar.map(|arc| f(arc, br.next().unwrap()))
+    })
+}
\ No newline at end of file
@@ -1,10 +1,8 @@
 use std::ops::{Mul, Add};
 
-use crate::{matrix_init, Matrix};
+use crate::{matrix_init, Matrix, Column};
 
 pub trait MMul<Rhs>: Matrix
-where
-    Self::Output: Matrix
 {
     type Output;
     
@@ -14,7 +12,7 @@ where
     /// 
     /// # Arguments
     /// 
-    /// * `rhs` - A scalar or a matrix with height equal this matrix's length
+    /// * `rhs` - A scalar, a vector with length equal this matrix's length, or a matrix with height equal this matrix's length
     /// 
     /// # Examples
     /// 
@@ -46,6 +44,14 @@ where
     ///     [3.0, 6.0, 9.0]
     /// ];
     /// assert_eq!(a.mul(b), ab)
+    /// 
+    /// // Matrix-vector multiplication
+    /// let a = [
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0]
+    /// ];
+    /// let x = [1.0, -1.0];
+    /// assert_eq!(a.mul(x), [-1.0, -1.0])
     /// ```
     fn mul(self, rhs: Rhs) -> Self::Output;
 }
@@ -77,4 +83,29 @@ where
     {
         matrix_init(|r, c| (0..L).map(|i| self[r][i].clone()*rhs[i][c].clone()).reduce(|a, b| a + b).unwrap())
     }
+}
+
+impl<F, const L: usize, const H: usize> MMul<[F; L]> for [[F; L]; H]
+where
+    Self: Matrix,
+    F: Clone + Mul<F>,
+    <F as Mul<F>>::Output: Add<<F as Mul<F>>::Output, Output = <F as Mul<F>>::Output>
+{
+    type Output = [<F as Mul<F>>::Output; H];
+    fn mul(self, rhs: [F; L]) -> Self::Output
+    {
+        array_init::array_init(|r| (0..L).map(|i| self[r][i].clone()*rhs[i].clone()).reduce(|a, b| a + b).unwrap())
+    }
+}
+
+impl<F, const L: usize, const H: usize> MMul<Column<F, L>> for [[F; L]; H]
+where
+    Self: MMul<[F; L], Output = [<F as Mul<F>>::Output; H]>,
+    F: Mul<F>
+{
+    type Output = Column<<F as Mul<F>>::Output, H>;
+    fn mul(self, rhs: Column<F, L>) -> Self::Output
+    {
+        Column(self.mul(rhs.0))
+    }
 }
\ No newline at end of file
@@ -0,0 +1,192 @@
+use num_complex::ComplexFloat;
+use num_traits::{Float, Zero};
+
+use crate::{Matrix, Svd, LinalgError};
+
+pub trait MNorm: Matrix
+{
+    type Output;
+
+    /// Returns the Frobenius norm of the given matrix
+    /// 
+    /// ||A||_F = √(Σᵢⱼ|aᵢⱼ|²)
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, 2.0],
+    ///     [2.0, 4.0]
+    /// ];
+    /// assert_eq!(a.norm_fro(), 5.0);
+    /// ```
+    fn norm_fro(&self) -> Self::Output;
+
+    /// Returns the 1-norm of the given matrix, which is the largest absolute column sum
+    /// 
+    /// ||A||₁ = maxⱼ Σᵢ|aᵢⱼ|
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, -2.0],
+    ///     [3.0, 4.0]
+    /// ];
+    /// assert_eq!(a.norm_1(), 6.0);
+    /// ```
+    fn norm_1(&self) -> Self::Output;
+
+    /// Returns the ∞-norm of the given matrix, which is the largest absolute row sum
+    /// 
+    /// ||A||∞ = maxᵢ Σⱼ|aᵢⱼ|
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, -2.0],
+    ///     [3.0, 4.0]
+    /// ];
+    /// assert_eq!(a.norm_inf(), 7.0);
+    /// ```
+    fn norm_inf(&self) -> Self::Output;
+
+    /// Returns the spectral norm of the given matrix, which is its largest singular value, if the singular value decomposition converges
+    /// 
+    /// ||A||₂ = σ₁
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [3.0, 0.0],
+    ///     [0.0, -4.0]
+    /// ];
+    /// assert_eq!(a.norm_2(), Some(4.0));
+    /// ```
+    fn norm_2(&self) -> Option<Self::Output>
+    where
+        Self: Svd<OutputS = Self>;
+
+    /// Returns the spectral norm of the given matrix, or an error if the singular value decomposition does not converge
+    /// 
+    /// ||A||₂ = σ₁
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a = [
+    ///     [3.0, 0.0],
+    ///     [0.0, -4.0]
+    /// ];
+    /// assert_eq!(a.try_norm_2()?, 4.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_norm_2(&self) -> Result<Self::Output, LinalgError>
+    where
+        Self: Svd<OutputS = Self>
+    {
+        self.norm_2().ok_or(LinalgError::NoConvergence)
+    }
+
+    /// Returns the nuclear norm of the given matrix, which is the sum of its singular values, if the singular value decomposition converges
+    /// 
+    /// ||A||* = Σᵢσᵢ
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [3.0, 0.0],
+    ///     [0.0, -4.0]
+    /// ];
+    /// assert_eq!(a.norm_nuclear(), Some(7.0));
+    /// ```
+    fn norm_nuclear(&self) -> Option<Self::Output>
+    where
+        Self: Svd<OutputS = Self>;
+
+    /// Returns the nuclear norm of the given matrix, or an error if the singular value decomposition does not converge
+    /// 
+    /// ||A||* = Σᵢσᵢ
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a = [
+    ///     [3.0, 0.0],
+    ///     [0.0, -4.0]
+    /// ];
+    /// assert_eq!(a.try_norm_nuclear()?, 7.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_norm_nuclear(&self) -> Result<Self::Output, LinalgError>
+    where
+        Self: Svd<OutputS = Self>
+    {
+        self.norm_nuclear().ok_or(LinalgError::NoConvergence)
+    }
+}
+
+impl<F: ComplexFloat, const L: usize, const H: usize> MNorm for [[F; L]; H]
+where
+    Self: Matrix
+{
+    type Output = F::Real;
+
+    fn norm_fro(&self) -> Self::Output
+    {
+        Float::sqrt(self.iter()
+            .flatten()
+            .map(|arc| arc.abs()*arc.abs())
+            .fold(Zero::zero(), |a, b| a + b))
+    }
+
+    fn norm_1(&self) -> Self::Output
+    {
+        (0..L)
+            .map(|c| self.iter()
+                .map(|ar| ar[c].abs())
+                .fold(Zero::zero(), |a: F::Real, b| a + b)
+            ).fold(Zero::zero(), Float::max)
+    }
+
+    fn norm_inf(&self) -> Self::Output
+    {
+        self.iter()
+            .map(|ar| ar.iter()
+                .map(|arc| arc.abs())
+                .fold(Zero::zero(), |a: F::Real, b| a + b)
+            ).fold(Zero::zero(), Float::max)
+    }
+
+    fn norm_2(&self) -> Option<Self::Output>
+    where
+        Self: Svd<OutputS = Self>
+    {
+        let (_, s, _) = self.svd()?;
+        Some(s.first()
+            .and_then(|s0| s0.first())
+            .map(|s00| s00.abs())
+            .unwrap_or(Zero::zero()))
+    }
+
+    fn norm_nuclear(&self) -> Option<Self::Output>
+    where
+        Self: Svd<OutputS = Self>
+    {
+        let (_, s, _) = self.svd()?;
+        Some((0..L.min(H))
+            .map(|i| s[i][i].abs())
+            .fold(Zero::zero(), |a, b| a + b))
+    }
+}
\ No newline at end of file
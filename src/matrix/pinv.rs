@@ -0,0 +1,138 @@
+use num_complex::ComplexFloat;
+use num_traits::{Float, NumCast};
+
+use crate::{matrix_init, Matrix, Svd, LinalgError};
+
+pub trait PInv: Matrix
+{
+    type Output;
+    type Tolerance;
+
+    /// Returns the Moore-Penrose pseudoinverse of the given matrix if its singular value decomposition converges
+    /// 
+    /// A⁺ = VΣ⁺Uᴴ
+    /// 
+    /// Singular values below max(H, L)·σ₁·ε are treated as zero.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, 0.0],
+    ///     [0.0, 2.0],
+    ///     [0.0, 0.0]
+    /// ];
+    /// let ap = [
+    ///     [1.0, 0.0, 0.0],
+    ///     [0.0, 0.5, 0.0]
+    /// ];
+    /// assert_eq!(a.pinv(), Some(ap));
+    /// ```
+    fn pinv(&self) -> Option<Self::Output>;
+
+    /// Returns the Moore-Penrose pseudoinverse of the given matrix, or an error if its singular value decomposition does not converge
+    /// 
+    /// A⁺ = VΣ⁺Uᴴ
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a = [
+    ///     [1.0, 0.0],
+    ///     [0.0, 2.0]
+    /// ];
+    /// let ap = a.try_pinv()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_pinv(&self) -> Result<Self::Output, LinalgError>
+    {
+        self.pinv().ok_or(LinalgError::NoConvergence)
+    }
+
+    /// Returns the Moore-Penrose pseudoinverse of the given matrix if its singular value decomposition converges
+    /// 
+    /// A⁺ = VΣ⁺Uᴴ
+    /// 
+    /// # Arguments
+    /// 
+    /// * `tolerance` - Singular values at or below this are treated as zero
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, 0.0],
+    ///     [0.0, 1e-12]
+    /// ];
+    /// let ap = [
+    ///     [1.0, 0.0],
+    ///     [0.0, 0.0]
+    /// ];
+    /// assert_eq!(a.pinv_with(1e-9), Some(ap));
+    /// ```
+    fn pinv_with(&self, tolerance: Self::Tolerance) -> Option<Self::Output>;
+
+    /// Returns the Moore-Penrose pseudoinverse of the given matrix, or an error if its singular value decomposition does not converge
+    /// 
+    /// A⁺ = VΣ⁺Uᴴ
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a = [
+    ///     [1.0, 0.0],
+    ///     [0.0, 1e-12]
+    /// ];
+    /// let ap = a.try_pinv_with(1e-9)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_pinv_with(&self, tolerance: Self::Tolerance) -> Result<Self::Output, LinalgError>
+    {
+        self.pinv_with(tolerance).ok_or(LinalgError::NoConvergence)
+    }
+}
+
+impl<F: ComplexFloat, const L: usize, const H: usize> PInv for [[F; L]; H]
+where
+    Self: Svd<OutputU = [[F; H]; H], OutputS = [[F; L]; H], OutputVh = [[F; L]; L]>
+{
+    type Output = [[F; H]; L];
+    type Tolerance = F::Real;
+
+    fn pinv(&self) -> Option<Self::Output>
+    {
+        let (u, s, vh) = self.svd()?;
+        let tolerance = s[0][0].abs()*F::Real::epsilon()*<F::Real as NumCast>::from(H.max(L)).unwrap();
+        Some(pinv_from_svd(&u, &s, &vh, tolerance))
+    }
+
+    fn pinv_with(&self, tolerance: Self::Tolerance) -> Option<Self::Output>
+    {
+        let (u, s, vh) = self.svd()?;
+        Some(pinv_from_svd(&u, &s, &vh, tolerance))
+    }
+}
+
+fn pinv_from_svd<F: ComplexFloat, const L: usize, const H: usize>(
+    u: &[[F; H]; H],
+    s: &[[F; L]; H],
+    vh: &[[F; L]; L],
+    tolerance: F::Real
+) -> [[F; H]; L]
+{
+    let rank = (0..H.min(L))
+        .take_while(|&k| s[k][k].abs() > tolerance)
+        .count();
+    matrix_init(|r, c| (0..rank)
+        .map(|k| vh[k][r].conj()*u[c][k].conj()/s[k][k])
+        .fold(F::zero(), |a, b| a + b)
+    )
+}
\ No newline at end of file
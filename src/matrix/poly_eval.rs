@@ -0,0 +1,55 @@
+use std::ops::{Mul, Add};
+
+use num_traits::{Zero, One};
+
+use crate::{matrix_init, SquareMatrix};
+
+pub trait PolyEval: SquareMatrix
+{
+    type Scalar;
+    type Output;
+
+    /// Returns the polynomial evaluated at the given matrix, with the coefficients starting with the leading one
+    /// 
+    /// p(A) = p₀Aⁿ + p₁Aⁿ⁻¹ + ... + pₙI
+    /// 
+    /// Uses Horner's method.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `p` - The coefficients of the polynomial
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// // Cayley-Hamilton: a matrix is a root of its characteristic polynomial
+    /// let a = [
+    ///     [1, 2],
+    ///     [3, 4]
+    /// ];
+    /// assert_eq!(a.poly_eval(&a.char_poly()), [[0, 0], [0, 0]]);
+    /// ```
+    fn poly_eval(&self, p: &[Self::Scalar]) -> Self::Output;
+}
+
+impl<F, const N: usize> PolyEval for [[F; N]; N]
+where
+    Self: SquareMatrix,
+    F: Clone + Zero + One + Add<F, Output = F> + Mul<F, Output = F>
+{
+    type Scalar = F;
+    type Output = Self;
+
+    fn poly_eval(&self, p: &[Self::Scalar]) -> Self::Output
+    {
+        let mut b: Self = matrix_init(|_, _| F::zero());
+        for pk in p.iter()
+        {
+            b = matrix_init(|r, k| (0..N)
+                .map(|i| self[r][i].clone()*b[i][k].clone())
+                .fold(if r == k {pk.clone()} else {F::zero()}, |a, b| a + b)
+            );
+        }
+        b
+    }
+}
\ No newline at end of file
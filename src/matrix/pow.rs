@@ -0,0 +1,122 @@
+use crate::{MInv, MMul, SquareMatrix, LinalgError};
+
+pub trait MPow: SquareMatrix
+{
+    type Output;
+
+    /// Returns the matrix raised to a non-negative integer power
+    /// 
+    /// Aⁿ
+    /// 
+    /// Uses binary exponentiation, so only about 2·log₂(n) matrix products are needed.
+    /// Only needs multiplication, so it also works for integer matrices.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `n` - The exponent
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// // Fibonacci numbers
+    /// let a: [[i64; 2]; 2] = [
+    ///     [1, 1],
+    ///     [1, 0]
+    /// ];
+    /// assert_eq!(a.powu(10), [[89, 55], [55, 34]]);
+    /// ```
+    fn powu(&self, n: u32) -> Self::Output;
+
+    /// Returns the matrix raised to an integer power, if the matrix is non-singular or the power is non-negative
+    /// 
+    /// Aⁿ
+    /// 
+    /// Negative powers are powers of the inverse matrix.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `n` - The exponent
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [2.0, 1.0],
+    ///     [1.0, 1.0]
+    /// ];
+    /// assert_eq!(a.powi(-2), Some([[2.0, -3.0], [-3.0, 5.0]]));
+    /// 
+    /// // Returns none for negative powers of a singular matrix
+    /// let a = [
+    ///     [1.0, 2.0],
+    ///     [2.0, 4.0]
+    /// ];
+    /// assert_eq!(a.powi(-1), None);
+    /// ```
+    fn powi(&self, n: i32) -> Option<<Self as MPow>::Output>
+    where
+        Self: MInv<Output = Self>
+    {
+        if n < 0
+        {
+            Some(self.inv()?.powu(n.unsigned_abs()))
+        }
+        else
+        {
+            Some(self.powu(n.unsigned_abs()))
+        }
+    }
+
+    /// Returns the matrix raised to an integer power, or an error if the power is negative and the matrix is singular
+    /// 
+    /// Aⁿ
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a = [
+    ///     [1.0, 2.0],
+    ///     [2.0, 4.0]
+    /// ];
+    /// assert_eq!(a.try_powi(-1), Err(LinalgError::Singular));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_powi(&self, n: i32) -> Result<<Self as MPow>::Output, LinalgError>
+    where
+        Self: MInv<Output = Self>
+    {
+        self.powi(n).ok_or(LinalgError::Singular)
+    }
+}
+
+impl<F, const N: usize> MPow for [[F; N]; N]
+where
+    F: Clone,
+    Self: SquareMatrix + MMul<Self, Output = Self>
+{
+    type Output = Self;
+
+    fn powu(&self, n: u32) -> Self::Output
+    {
+        let mut base = self.clone();
+        let mut e = n;
+        let mut an = Self::identity();
+        while e > 0
+        {
+            if e & 1 == 1
+            {
+                an = an.mul(base.clone());
+            }
+            e >>= 1;
+            if e > 0
+            {
+                base = base.clone().mul(base);
+            }
+        }
+        an
+    }
+}
\ No newline at end of file
@@ -0,0 +1,98 @@
+use num_complex::ComplexFloat;
+use num_traits::Float;
+
+use crate::{Matrix, SquareMatrix};
+
+pub trait QRGivens: Matrix
+{
+    type OutputQ;
+    type OutputR;
+
+    /// Returns the QR-decomposition of the given matrix using Givens rotations
+    /// 
+    /// A = QR
+    /// 
+    /// Q is unitary and R is upper-triangular.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0],
+    ///     [5.0, 6.0]
+    /// ];
+    /// let (q, r) = a.qr_givens();
+    /// ```
+    fn qr_givens(&self) -> (Self::OutputQ, Self::OutputR);
+}
+
+impl<F: ComplexFloat, const L: usize, const H: usize> QRGivens for [[F; L]; H]
+where
+    Self: Matrix,
+    [[F; H]; H]: SquareMatrix
+{
+    type OutputQ = [[F; H]; H];
+    type OutputR = [[F; L]; H];
+
+    fn qr_givens(&self) -> (Self::OutputQ, Self::OutputR)
+    {
+        let mut q = SquareMatrix::identity();
+        let mut r = *self;
+        triangularize(&mut q, &mut r, 0);
+        (q, r)
+    }
+}
+
+/// Zeros the elements below the diagonal of `r` from row `from` down, by Givens rotations, keeping QR unchanged.
+pub(crate) fn triangularize<F: ComplexFloat, const L: usize, const H: usize>(q: &mut [[F; H]; H], r: &mut [[F; L]; H], from: usize)
+{
+    for k in 0..L.min(H)
+    {
+        for i in (k + 1).max(from)..H
+        {
+            if !r[i][k].is_zero()
+            {
+                let (c, s) = givens(r[k][k], r[i][k]);
+                rotate_rows(r, k, i, c, s);
+                rotate_columns(q, k, i, c, s);
+                r[i][k] = F::zero();
+            }
+        }
+    }
+}
+
+/// Returns the Givens rotation (c, s), with c real, such that [[c, s], [-s*, c]][x, y]ᵀ = [r, 0]ᵀ.
+pub(crate) fn givens<F: ComplexFloat>(x: F, y: F) -> (F, F)
+{
+    if y.is_zero()
+    {
+        return (F::one(), F::zero())
+    }
+    if x.is_zero()
+    {
+        return (F::zero(), y.conj()/F::from(y.abs()).unwrap())
+    }
+    let r = F::from(Float::hypot(x.abs(), y.abs())).unwrap();
+    let x_abs = F::from(x.abs()).unwrap();
+    (x_abs/r, x/x_abs*y.conj()/r)
+}
+
+/// Applies the Givens rotation [[c, s], [-s*, c]] to rows `i` and `j` of `a`.
+pub(crate) fn rotate_rows<F: ComplexFloat, const L: usize, const H: usize>(a: &mut [[F; L]; H], i: usize, j: usize, c: F, s: F)
+{
+    let [ai, aj] = a.get_disjoint_mut([i, j]).unwrap();
+    for (x, y) in ai.iter_mut().zip(aj.iter_mut())
+    {
+        (*x, *y) = (c**x + s**y, c**y - s.conj()**x);
+    }
+}
+
+/// Applies the conjugate transpose of the Givens rotation [[c, s], [-s*, c]] to columns `i` and `j` of `a`.
+pub(crate) fn rotate_columns<F: ComplexFloat, const L: usize, const H: usize>(a: &mut [[F; L]; H], i: usize, j: usize, c: F, s: F)
+{
+    for ar in a.iter_mut()
+    {
+        (ar[i], ar[j]) = (ar[i]*c + ar[j]*s.conj(), ar[j]*c - ar[i]*s);
+    }
+}
\ No newline at end of file
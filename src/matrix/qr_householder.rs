@@ -1,7 +1,7 @@
-use num_complex::Complex;
+use num_complex::{Complex, ComplexFloat};
 use num_traits::{Float, Zero, One};
 
-use crate::{Matrix, SquareMatrix, matrix_init, MMul, Transpose};
+use crate::{Matrix, SquareMatrix, LinalgError, matrix_init, MMul, Herm, hessenberg::norm};
 
 pub trait QRHouseholder: Matrix
 {
@@ -22,6 +22,33 @@ pub trait QRHouseholder: Matrix
     /// let (q, r) = a.qr_householder();
     /// ```
     fn qr_householder(&self) -> (Self::OutputQ, Self::OutputR);
+
+    /// Returns the Householder QR-decomposition of the given matrix, or an error if the matrix is wider than it is tall
+    /// 
+    /// A = QR
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a = [
+    ///     [1.0, 2.0, 3.0],
+    ///     [4.0, 5.0, 6.0]
+    /// ];
+    /// assert_eq!(a.try_qr_householder(), Err(LinalgError::DimensionMismatch));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_qr_householder(&self) -> Result<(Self::OutputQ, Self::OutputR), LinalgError>
+    {
+        if self.height() < self.length()
+        {
+            return Err(LinalgError::DimensionMismatch)
+        }
+        Ok(self.qr_householder())
+    }
 }
 
 impl<F: Float, const L: usize, const H: usize> QRHouseholder for [[Complex<F>; L]; H]
@@ -51,7 +78,7 @@ where
                 .reduce(|a, b| a + b)
                 .unwrap_or(F::zero())
                 .sqrt();
-            let alpha = -Complex::cis(x[t].arg())*x_abs;
+            let alpha = -Complex::cis(x[0].arg())*x_abs;
             let mut u = x;
             u[0] = u[0] - alpha;
             let u_abs = u.iter()
@@ -60,7 +87,7 @@ where
                 .unwrap_or(F::zero())
                 .sqrt();
             let v: Vec<Complex<F>> = u.iter()
-                .map(|un| un/u_abs)
+                .map(|un| if u_abs.is_zero() {Complex::zero()} else {un/u_abs})
                 .collect();
             let q_: Vec<Vec<Complex<F>>> = (0..H - t)
                 .map(|r| (0..H - t)
@@ -69,7 +96,7 @@ where
                 ).collect();
             let qt: [[Complex<F>; H]; H] = matrix_init(|r, c| if r >= t && c >= t
                 {
-                    q_[c - t][r - t]
+                    q_[r - t][c - t]
                 }
                 else
                 {
@@ -86,7 +113,7 @@ where
                     ).collect()
                 ).collect()
         }
-        let r = q.transpose().mul(self.clone());
+        let r = q.herm().mul(self.clone());
         return (q, r)
     }
 }
@@ -115,4 +142,178 @@ where
     {
         self.map(|ar| ar.map(|arc| Complex::from(arc))).qr_householder()
     }
+}
+
+pub trait QRHouseholderPivoted: Matrix
+{
+    type OutputQ;
+    type OutputR;
+    type OutputP;
+    type Tolerance;
+
+    /// Returns the Householder QR-decomposition of the given matrix using column pivoting
+    /// 
+    /// AP = QR
+    /// 
+    /// P is a permutation matrix chosen such that the diagonal of R is non-increasing in magnitude.
+    /// Works for rank-deficient and wide matrices.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, 2.0, 3.0],
+    ///     [2.0, 4.0, 6.0]
+    /// ];
+    /// let (q, r, p) = a.qr_householder_pivoted();
+    /// ```
+    fn qr_householder_pivoted(&self) -> (Self::OutputQ, Self::OutputR, Self::OutputP);
+
+    /// Returns the numerical rank of the given matrix, estimated from the column-pivoted QR-decomposition
+    /// 
+    /// rank(A)
+    /// 
+    /// Uses machine precision times the largest dimension as relative tolerance.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, 2.0, 3.0],
+    ///     [2.0, 4.0, 6.0]
+    /// ];
+    /// assert_eq!(a.rank(), 1);
+    /// ```
+    fn rank(&self) -> usize;
+
+    /// Returns the numerical rank of the given matrix, estimated from the column-pivoted QR-decomposition
+    /// 
+    /// rank(A)
+    /// 
+    /// # Arguments
+    /// 
+    /// * `tolerance` - Size of a diagonal element of R, relative to the largest one, below which it is considered zero
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, 0.0],
+    ///     [0.0, 1e-9]
+    /// ];
+    /// assert_eq!(a.rank_with(1e-6), 1);
+    /// ```
+    fn rank_with(&self, tolerance: Self::Tolerance) -> usize;
+}
+
+impl<F: ComplexFloat, const L: usize, const H: usize> QRHouseholderPivoted for [[F; L]; H]
+where
+    Self: Matrix,
+    [[F; H]; H]: SquareMatrix
+{
+    type OutputQ = [[F; H]; H];
+    type OutputR = [[F; L]; H];
+    type OutputP = [[F; L]; L];
+    type Tolerance = F::Real;
+
+    fn qr_householder_pivoted(&self) -> (Self::OutputQ, Self::OutputR, Self::OutputP)
+    {
+        let mut q = SquareMatrix::identity();
+        let mut r = *self;
+        let perm = qr_pivoted_in_place(&mut r, &mut q);
+        let p = matrix_init(|r, c| if perm[c] == r {F::one()} else {F::zero()});
+        (q, r, p)
+    }
+
+    fn rank(&self) -> usize
+    {
+        let dim = <F::Real as num_traits::NumCast>::from(H.max(L)).unwrap();
+        self.rank_with(<F::Real as Float>::epsilon()*dim)
+    }
+
+    fn rank_with(&self, tolerance: Self::Tolerance) -> usize
+    {
+        let mut q = SquareMatrix::identity();
+        let mut r = *self;
+        qr_pivoted_in_place(&mut r, &mut q);
+        let r_max = r.first()
+            .and_then(|r0| r0.first())
+            .map(|r00| r00.abs())
+            .unwrap_or(Zero::zero());
+        (0..H.min(L))
+            .take_while(|&k| r[k][k].abs() > tolerance*r_max)
+            .count()
+    }
+}
+
+/// Performs Householder QR-decomposition with column pivoting in place, applying the reflections to the columns of `q`.
+/// 
+/// At each step the remaining column with the largest norm is moved to the front.
+/// Returns the column permutation, such that column `c` of AP is column `perm[c]` of A.
+pub(crate) fn qr_pivoted_in_place<F: ComplexFloat, const L: usize, const H: usize>(r: &mut [[F; L]; H], q: &mut [[F; H]; H]) -> [usize; L]
+{
+    let two = F::one() + F::one();
+    let mut perm: [usize; L] = array_init::array_init(|i| i);
+    for k in 0..H.min(L)
+    {
+        let column_norm = |r: &[[F; L]; H], c: usize| norm(&r[k..].iter().map(|rr| rr[c]).collect::<Vec<F>>());
+        let p = (k..L)
+            .map(|c| (c, column_norm(r, c)))
+            .reduce(|a, b| if b.1 > a.1 {b} else {a})
+            .unwrap();
+        if p.1.is_zero()
+        {
+            break
+        }
+        if p.0 != k
+        {
+            for rr in r.iter_mut()
+            {
+                rr.swap(p.0, k);
+            }
+            perm.swap(p.0, k);
+        }
+
+        let x_abs = F::from(p.1).unwrap();
+        let phase = if r[k][k].is_zero() {F::one()} else {r[k][k]/F::from(r[k][k].abs()).unwrap()};
+        let mut v = [F::zero(); H];
+        for (vi, ri) in v.iter_mut().zip(r.iter()).skip(k)
+        {
+            *vi = ri[k];
+        }
+        v[k] = v[k] + phase*x_abs;
+        let v_abs = F::from(norm(&v)).unwrap();
+        for vi in v.iter_mut()
+        {
+            *vi = *vi/v_abs;
+        }
+
+        // R = (I - 2vvᴴ)R, Q = Q(I - 2vvᴴ)
+        for c in k..L
+        {
+            let s = (k..H)
+                .map(|i| v[i].conj()*r[i][c])
+                .fold(F::zero(), |a, b| a + b);
+            for (ri, &vi) in r.iter_mut().zip(v.iter()).skip(k)
+            {
+                ri[c] = ri[c] - two*vi*s;
+            }
+        }
+        for qr in q.iter_mut()
+        {
+            let s = (k..H)
+                .map(|j| qr[j]*v[j])
+                .fold(F::zero(), |a, b| a + b);
+            for j in k..H
+            {
+                qr[j] = qr[j] - two*s*v[j].conj();
+            }
+        }
+        r[k][k] = -phase*x_abs;
+        for ri in r[k + 1..].iter_mut()
+        {
+            ri[k] = F::zero();
+        }
+    }
+    perm
 }
\ No newline at end of file
@@ -0,0 +1,153 @@
+use num_complex::ComplexFloat;
+
+use crate::qr_givens::{givens, rotate_rows, rotate_columns, triangularize};
+
+pub trait QRUpdate
+{
+    type Row;
+    type Column;
+    type OutputAdd;
+    type OutputRemove;
+
+    /// Returns the QR-decomposition of a rank-1 modification of the decomposed matrix, without decomposing from scratch
+    /// 
+    /// A + uvᴴ = Q'R'
+    /// 
+    /// Downdates are done by negating `u`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `u` - A vector with length equal the matrix's height
+    /// * `v` - A vector with length equal the matrix's length
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0]
+    /// ];
+    /// let (q, r) = (a.qr_givens()).rank1_update([1.0, 0.0], [0.0, 1.0]);
+    /// ```
+    fn rank1_update(&self, u: Self::Column, v: Self::Row) -> Self;
+
+    /// Returns the QR-decomposition of the decomposed matrix with a row inserted, without decomposing from scratch
+    /// 
+    /// # Arguments
+    /// 
+    /// * `k` - Index of the new row in the resulting matrix
+    /// * `row` - The new row
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0]
+    /// ];
+    /// let (q, r) = (a.qr_givens()).add_row(2, [5.0, 6.0]);
+    /// ```
+    fn add_row(&self, k: usize, row: Self::Row) -> Self::OutputAdd;
+
+    /// Returns the QR-decomposition of the decomposed matrix with a row removed, without decomposing from scratch
+    /// 
+    /// # Arguments
+    /// 
+    /// * `k` - Index of the row to remove
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0],
+    ///     [5.0, 6.0]
+    /// ];
+    /// let (q, r) = (a.qr_givens()).remove_row(0);
+    /// ```
+    fn remove_row(&self, k: usize) -> Self::OutputRemove;
+}
+
+impl<F: ComplexFloat, const L: usize, const H: usize> QRUpdate for ([[F; H]; H], [[F; L]; H])
+where
+    [(); H + 1]:,
+    [(); H - 1]:
+{
+    type Row = [F; L];
+    type Column = [F; H];
+    type OutputAdd = ([[F; H + 1]; H + 1], [[F; L]; H + 1]);
+    type OutputRemove = ([[F; H - 1]; H - 1], [[F; L]; H - 1]);
+
+    fn rank1_update(&self, u: Self::Column, v: Self::Row) -> Self
+    {
+        let (mut q, mut r) = *self;
+
+        // w = Qᴴu, rotated onto the first axis while R becomes upper Hessenberg
+        let mut w: [F; H] = array_init::array_init(|j| (0..H)
+            .map(|i| q[i][j].conj()*u[i])
+            .fold(F::zero(), |a, b| a + b)
+        );
+        for k in (1..H).rev()
+        {
+            let (c, s) = givens(w[k - 1], w[k]);
+            (w[k - 1], w[k]) = (c*w[k - 1] + s*w[k], F::zero());
+            rotate_rows(&mut r, k - 1, k, c, s);
+            rotate_columns(&mut q, k - 1, k, c, s);
+        }
+        if let (Some(r0), Some(&w0)) = (r.first_mut(), w.first())
+        {
+            for (r0c, vc) in r0.iter_mut().zip(v)
+            {
+                *r0c = *r0c + w0*vc.conj();
+            }
+        }
+
+        // Restore the triangular form by zeroing the subdiagonal
+        for k in 0..L.min(H.saturating_sub(1))
+        {
+            let (c, s) = givens(r[k][k], r[k + 1][k]);
+            rotate_rows(&mut r, k, k + 1, c, s);
+            rotate_columns(&mut q, k, k + 1, c, s);
+            r[k + 1][k] = F::zero();
+        }
+        (q, r)
+    }
+
+    fn add_row(&self, k: usize, row: Self::Row) -> Self::OutputAdd
+    {
+        assert!(k <= H, "Row index out of bounds");
+        let (q, r) = self;
+
+        // The new row is appended to R, and Q is extended so that it lands at index k
+        let mut r: [[F; L]; H + 1] = array_init::array_init(|i| if i < H {r[i]} else {row});
+        let mut q: [[F; H + 1]; H + 1] = array_init::array_init(|i| if i == k
+            {
+                array_init::array_init(|j| if j == H {F::one()} else {F::zero()})
+            }
+            else
+            {
+                let qi = q[if i < k {i} else {i - 1}];
+                array_init::array_init(|j| if j < H {qi[j]} else {F::zero()})
+            }
+        );
+        triangularize(&mut q, &mut r, H);
+        (q, r)
+    }
+
+    fn remove_row(&self, k: usize) -> Self::OutputRemove
+    {
+        assert!(k < H, "Row index out of bounds");
+        let (mut q, mut r) = *self;
+
+        // Rotate row k of Q onto the first axis, which makes the first column of Q the k-th unit vector
+        for j in (1..H).rev()
+        {
+            let (c, s) = givens(q[k][j - 1].conj(), q[k][j].conj());
+            rotate_rows(&mut r, j - 1, j, c, s);
+            rotate_columns(&mut q, j - 1, j, c, s);
+        }
+        let q = array_init::array_init(|i| array_init::array_init(|j| q[if i < k {i} else {i + 1}][j + 1]));
+        let r = array_init::array_init(|i| r[i + 1]);
+        (q, r)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,45 @@
+use std::ops::MulAssign;
+
+use crate::Matrix;
+
+pub trait MScaleMut<Rhs>: Matrix
+{
+    /// Multiplies every element of this matrix by a scalar in place
+    /// 
+    /// A ← Ab
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rhs` - The scalar factor
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let mut a = [
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0]
+    /// ];
+    /// let p = a.mul(2.0);
+    /// a.scale_mut(2.0);
+    /// assert_eq!(a, p);
+    /// ```
+    fn scale_mut(&mut self, rhs: Rhs);
+}
+
+impl<F, Rhs, const H: usize, const L: usize> MScaleMut<Rhs> for [[F; L]; H]
+where
+    Self: Matrix,
+    F: MulAssign<Rhs>,
+    Rhs: Clone
+{
+    fn scale_mut(&mut self, rhs: Rhs)
+    {
+        for row in self.iter_mut()
+        {
+            for x in row.iter_mut()
+            {
+                *x *= rhs.clone()
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,487 @@
+use num_complex::Complex;
+use num_traits::{Float, FloatConst, Zero};
+
+use crate::{SquareMatrix, LinalgError, hessenberg::hessenberg_in_place};
+
+pub trait Schur: SquareMatrix
+{
+    type OutputQ;
+    type OutputT;
+    type Tolerance;
+
+    /// Returns the Schur-decomposition of the given matrix if the shifted QR-iteration converges
+    /// 
+    /// A = QTQᴴ
+    /// 
+    /// Q is unitary. For complex matrices T is upper-triangular with the eigenvalues on its diagonal.
+    /// For real matrices T is quasi upper-triangular, where 2×2 blocks on the diagonal hold complex-conjugate pairs of eigenvalues.
+    /// 
+    /// Uses machine precision as tolerance, and at most 30 QR-steps per eigenvalue.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [0.0, -1.0],
+    ///     [1.0, 0.0]
+    /// ];
+    /// let (q, t) = a.schur().unwrap();
+    /// ```
+    fn schur(&self) -> Option<(Self::OutputQ, Self::OutputT)>;
+
+    /// Returns the Schur-decomposition of the given matrix, or an error if the shifted QR-iteration does not converge
+    /// 
+    /// A = QTQᴴ
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a = [
+    ///     [0.0, -1.0],
+    ///     [1.0, 0.0]
+    /// ];
+    /// let (q, t) = a.try_schur()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_schur(&self) -> Result<(Self::OutputQ, Self::OutputT), LinalgError>
+    {
+        self.schur().ok_or(LinalgError::NoConvergence)
+    }
+
+    /// Returns the Schur-decomposition of the given matrix if the shifted QR-iteration converges within the given limits
+    /// 
+    /// A = QTQᴴ
+    /// 
+    /// # Arguments
+    /// 
+    /// * `tolerance` - Relative size of a subdiagonal element below which it is considered zero
+    /// * `max_iterations` - Maximum number of QR-steps in total
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0]
+    /// ];
+    /// let (q, t) = a.schur_with(1e-9, 100).unwrap();
+    /// ```
+    fn schur_with(&self, tolerance: Self::Tolerance, max_iterations: usize) -> Option<(Self::OutputQ, Self::OutputT)>;
+
+    /// Returns the Schur-decomposition of the given matrix, or an error if the shifted QR-iteration does not converge within the given limits
+    /// 
+    /// A = QTQᴴ
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a = [
+    ///     [1.0, 2.0, 3.0],
+    ///     [4.0, 5.0, 6.0],
+    ///     [7.0, 8.0, 10.0]
+    /// ];
+    /// assert_eq!(a.try_schur_with(1e-9, 0).map(|_| ()), Err(LinalgError::NoConvergence));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_schur_with(&self, tolerance: Self::Tolerance, max_iterations: usize) -> Result<(Self::OutputQ, Self::OutputT), LinalgError>
+    {
+        self.schur_with(tolerance, max_iterations).ok_or(LinalgError::NoConvergence)
+    }
+}
+
+impl<F: Float + FloatConst, const N: usize> Schur for [[Complex<F>; N]; N]
+where
+    Self: SquareMatrix
+{
+    type OutputQ = Self;
+    type OutputT = Self;
+    type Tolerance = F;
+
+    fn schur(&self) -> Option<(Self::OutputQ, Self::OutputT)>
+    {
+        self.schur_with(F::epsilon(), ITERATIONS_PER_EIGENVALUE*N)
+    }
+
+    fn schur_with(&self, tolerance: Self::Tolerance, max_iterations: usize) -> Option<(Self::OutputQ, Self::OutputT)>
+    {
+        let mut t = *self;
+        let mut q = Self::identity();
+        if !schur_in_place(&mut t, &mut q, tolerance, max_iterations)
+        {
+            return None
+        }
+        Some((q, t))
+    }
+}
+
+impl<const N: usize> Schur for [[f32; N]; N]
+where
+    Self: SquareMatrix
+{
+    type OutputQ = Self;
+    type OutputT = Self;
+    type Tolerance = f32;
+
+    fn schur(&self) -> Option<(Self::OutputQ, Self::OutputT)>
+    {
+        self.schur_with(f32::EPSILON, ITERATIONS_PER_EIGENVALUE*N)
+    }
+
+    fn schur_with(&self, tolerance: Self::Tolerance, max_iterations: usize) -> Option<(Self::OutputQ, Self::OutputT)>
+    {
+        let mut t = *self;
+        let mut q = Self::identity();
+        if !real_schur_in_place(&mut t, &mut q, tolerance, max_iterations)
+        {
+            return None
+        }
+        Some((q, t))
+    }
+}
+
+impl<const N: usize> Schur for [[f64; N]; N]
+where
+    Self: SquareMatrix
+{
+    type OutputQ = Self;
+    type OutputT = Self;
+    type Tolerance = f64;
+
+    fn schur(&self) -> Option<(Self::OutputQ, Self::OutputT)>
+    {
+        self.schur_with(f64::EPSILON, ITERATIONS_PER_EIGENVALUE*N)
+    }
+
+    fn schur_with(&self, tolerance: Self::Tolerance, max_iterations: usize) -> Option<(Self::OutputQ, Self::OutputT)>
+    {
+        let mut t = *self;
+        let mut q = Self::identity();
+        if !real_schur_in_place(&mut t, &mut q, tolerance, max_iterations)
+        {
+            return None
+        }
+        Some((q, t))
+    }
+}
+
+/// Default limit on the number of QR-steps per eigenvalue
+pub(crate) const ITERATIONS_PER_EIGENVALUE: usize = 30;
+
+/// Computes the complex Schur form A = QTQᴴ in place, with T upper-triangular, by shifted and deflating QR-iteration on the Hessenberg form.
+/// 
+/// `t` starts out as A and `q` as the identity matrix.
+/// Returns false if some eigenvalue has not converged after `max_iterations` QR-steps in total.
+pub(crate) fn schur_in_place<F: Float + FloatConst, const N: usize>(
+    t: &mut [[Complex<F>; N]; N],
+    q: &mut [[Complex<F>; N]; N],
+    tolerance: F,
+    max_iterations: usize
+) -> bool
+{
+    hessenberg_in_place(t, q);
+
+    let norm = t.iter()
+        .flatten()
+        .map(|tn| tn.norm())
+        .fold(F::zero(), |a, b| a + b);
+    let mut iterations = 0;
+    let mut since_deflation = 0;
+    let mut hi = N.saturating_sub(1);
+    while hi > 0
+    {
+        // Find the start of the active block by looking for a negligible subdiagonal element
+        let mut lo = hi;
+        while lo > 0
+        {
+            let s = t[lo - 1][lo - 1].norm() + t[lo][lo].norm();
+            let s = if s.is_zero() {norm} else {s};
+            if t[lo][lo - 1].norm() <= tolerance*s
+            {
+                t[lo][lo - 1] = Complex::zero();
+                break
+            }
+            lo -= 1;
+        }
+        if lo == hi
+        {
+            hi -= 1;
+            since_deflation = 0;
+            continue
+        }
+        if iterations >= max_iterations
+        {
+            return false
+        }
+        iterations += 1;
+        since_deflation += 1;
+
+        let mu = if since_deflation % 11 == 10
+        {
+            // Exceptional shift to break cycles
+            t[hi][hi] + Complex::from(t[hi][hi - 1].norm())
+        }
+        else
+        {
+            wilkinson_shift(t[hi - 1][hi - 1], t[hi - 1][hi], t[hi][hi - 1], t[hi][hi])
+        };
+
+        // Explicit QR step on the active block: T - μI = GR, T ← RG + μI
+        shift_diagonal(t, lo, hi, -mu);
+        let mut rotations = Vec::with_capacity(hi - lo);
+        for k in lo..hi
+        {
+            let (c, s) = givens(t[k][k], t[k + 1][k]);
+            let (upper, lower) = t.split_at_mut(k + 1);
+            for (x, y) in upper[k][k..].iter_mut().zip(lower[0][k..].iter_mut())
+            {
+                (*x, *y) = (*x*c + *y*s, *y*c - *x*s.conj());
+            }
+            rotations.push((c, s));
+        }
+        for (k, &(c, s)) in (lo..hi).zip(rotations.iter())
+        {
+            for tr in t[..=k + 1].iter_mut().chain(q.iter_mut())
+            {
+                (tr[k], tr[k + 1]) = (tr[k]*c + tr[k + 1]*s.conj(), tr[k + 1]*c - tr[k]*s);
+            }
+        }
+        shift_diagonal(t, lo, hi, mu);
+    }
+    true
+}
+
+/// Computes the real Schur form A = QTQᵀ in place by Francis double-shift QR-iteration on the Hessenberg form.
+/// 
+/// T is quasi upper-triangular, where 2×2 blocks on the diagonal hold complex-conjugate pairs of eigenvalues.
+/// `t` starts out as A and `q` as the identity matrix.
+/// Returns false if some eigenvalue has not converged after `max_iterations` QR-steps in total.
+pub(crate) fn real_schur_in_place<F: Float + FloatConst, const N: usize>(
+    t: &mut [[F; N]; N],
+    q: &mut [[F; N]; N],
+    tolerance: F,
+    max_iterations: usize
+) -> bool
+{
+    hessenberg_in_place(t, q);
+
+    let norm = t.iter()
+        .flatten()
+        .map(|tn| tn.abs())
+        .fold(F::zero(), |a, b| a + b);
+    let mut iterations = 0;
+    let mut since_deflation = 0;
+    let mut hi = N.saturating_sub(1);
+    while hi > 0
+    {
+        let mut lo = hi;
+        while lo > 0
+        {
+            let s = t[lo - 1][lo - 1].abs() + t[lo][lo].abs();
+            let s = if s.is_zero() {norm} else {s};
+            if t[lo][lo - 1].abs() <= tolerance*s
+            {
+                t[lo][lo - 1] = F::zero();
+                break
+            }
+            lo -= 1;
+        }
+        if lo == hi
+        {
+            hi -= 1;
+            since_deflation = 0;
+            continue
+        }
+        if lo + 1 == hi
+        {
+            split_block(t, q, lo);
+            hi = hi.saturating_sub(2);
+            since_deflation = 0;
+            continue
+        }
+        if iterations >= max_iterations
+        {
+            return false
+        }
+        iterations += 1;
+        since_deflation += 1;
+
+        // Sum and product of the two shifts, taken from the trailing 2×2 block
+        let m = hi - 1;
+        let (s, p) = if since_deflation % 11 == 10
+        {
+            // Exceptional shifts to break cycles
+            let w = t[hi][m].abs() + t[m][m - 1].abs();
+            (F::from(1.5).unwrap()*w, w*w)
+        }
+        else
+        {
+            (t[m][m] + t[hi][hi], t[m][m]*t[hi][hi] - t[m][hi]*t[hi][m])
+        };
+
+        // Chase the bulge from the first column of (T - μ₁I)(T - μ₂I) down the active block
+        let mut x = t[lo][lo]*t[lo][lo] + t[lo][lo + 1]*t[lo + 1][lo] - s*t[lo][lo] + p;
+        let mut y = t[lo + 1][lo]*(t[lo][lo] + t[lo + 1][lo + 1] - s);
+        let mut z = t[lo + 1][lo]*t[lo + 2][lo + 1];
+        for k in lo..hi - 1
+        {
+            let u_abs = (x*x + y*y + z*z).sqrt();
+            if !u_abs.is_zero()
+            {
+                let w = [x + x.signum()*u_abs, y, z];
+                let w_sqr = w[0]*w[0] + w[1]*w[1] + w[2]*w[2];
+                let two = F::one() + F::one();
+                let from = if k > lo {k - 1} else {lo};
+                let [r0, r1, r2] = t.get_disjoint_mut([k, k + 1, k + 2]).unwrap();
+                for ((a, b), c) in r0[from..].iter_mut().zip(r1[from..].iter_mut()).zip(r2[from..].iter_mut())
+                {
+                    let d = (w[0]**a + w[1]**b + w[2]**c)*two/w_sqr;
+                    (*a, *b, *c) = (*a - d*w[0], *b - d*w[1], *c - d*w[2]);
+                }
+                for tr in t[..=(k + 3).min(hi)].iter_mut().chain(q.iter_mut())
+                {
+                    let d = (w[0]*tr[k] + w[1]*tr[k + 1] + w[2]*tr[k + 2])*two/w_sqr;
+                    (tr[k], tr[k + 1], tr[k + 2]) = (tr[k] - d*w[0], tr[k + 1] - d*w[1], tr[k + 2] - d*w[2]);
+                }
+                if k > lo
+                {
+                    t[k + 1][k - 1] = F::zero();
+                    t[k + 2][k - 1] = F::zero();
+                }
+            }
+            x = t[k + 1][k];
+            y = t[k + 2][k];
+            if k + 3 <= hi
+            {
+                z = t[k + 3][k];
+            }
+        }
+        let (c, s) = real_givens(x, y);
+        rotate(t, q, hi - 1, hi - 2, hi, c, s);
+        t[hi][hi - 2] = F::zero();
+    }
+    true
+}
+
+/// Splits a converged 2×2 diagonal block starting at `k` into two 1×1 blocks if its eigenvalues are real.
+fn split_block<F: Float, const N: usize>(t: &mut [[F; N]; N], q: &mut [[F; N]; N], k: usize)
+{
+    let (a, b, c, d) = (t[k][k], t[k][k + 1], t[k + 1][k], t[k + 1][k + 1]);
+    if c.is_zero()
+    {
+        return
+    }
+    let two = F::one() + F::one();
+    let p = (a - d)/two;
+    let disc = p*p + b*c;
+    if disc < F::zero()
+    {
+        return
+    }
+    // Rotate the eigenvector of λ onto the first axis
+    let lambda = (a + d)/two + p.signum()*disc.sqrt();
+    let (x, y) = if b.abs() + (lambda - a).abs() >= (lambda - d).abs() + c.abs()
+    {
+        (b, lambda - a)
+    }
+    else
+    {
+        (lambda - d, c)
+    };
+    let r = x.hypot(y);
+    rotate(t, q, k, k, k + 1, x/r, y/r);
+    t[k + 1][k] = F::zero();
+}
+
+/// Applies the plane rotation [[c, s], [-s, c]] to rows `k` and `k + 1` of `t` from column `from`,
+/// and its transpose to the same columns of `t` up to row `to` and of `q`.
+fn rotate<F: Float, const N: usize>(t: &mut [[F; N]; N], q: &mut [[F; N]; N], k: usize, from: usize, to: usize, c: F, s: F)
+{
+    let (upper, lower) = t.split_at_mut(k + 1);
+    for (x, y) in upper[k][from..].iter_mut().zip(lower[0][from..].iter_mut())
+    {
+        (*x, *y) = (c**x + s**y, c**y - s**x);
+    }
+    for tr in t[..=to].iter_mut().chain(q.iter_mut())
+    {
+        (tr[k], tr[k + 1]) = (c*tr[k] + s*tr[k + 1], c*tr[k + 1] - s*tr[k]);
+    }
+}
+
+/// Returns the real Givens rotation (c, s) such that [[c, s], [-s, c]][x, y]ᵀ = [r, 0]ᵀ.
+fn real_givens<F: Float>(x: F, y: F) -> (F, F)
+{
+    if y.is_zero()
+    {
+        return (F::one(), F::zero())
+    }
+    let r = x.hypot(y);
+    (x/r, y/r)
+}
+
+/// Returns the eigenvalues on the diagonal of a real Schur form.
+pub(crate) fn real_schur_eigenvalues<F: Float, const N: usize>(t: &[[F; N]; N]) -> [Complex<F>; N]
+{
+    let mut lambda = [Complex::zero(); N];
+    let mut i = 0;
+    while i < N
+    {
+        if i + 1 < N && !t[i + 1][i].is_zero()
+        {
+            let (a, b, c, d) = (t[i][i], t[i][i + 1], t[i + 1][i], t[i + 1][i + 1]);
+            let two = F::one() + F::one();
+            let re = (a + d)/two;
+            let im = (-((a - d)*(a - d)/(two*two) + b*c)).max(F::zero()).sqrt();
+            lambda[i] = Complex::new(re, im);
+            lambda[i + 1] = Complex::new(re, -im);
+            i += 2;
+        }
+        else
+        {
+            lambda[i] = Complex::from(t[i][i]);
+            i += 1;
+        }
+    }
+    lambda
+}
+
+/// Adds `mu` to the diagonal elements from `lo` to `hi`.
+fn shift_diagonal<F: Float, const N: usize>(t: &mut [[Complex<F>; N]; N], lo: usize, hi: usize, mu: Complex<F>)
+{
+    for (k, tk) in t.iter_mut().enumerate().take(hi + 1).skip(lo)
+    {
+        tk[k] = tk[k] + mu;
+    }
+}
+
+/// Returns the eigenvalue of the 2×2 matrix [[a, b], [c, d]] closest to d.
+pub(crate) fn wilkinson_shift<F: Float>(a: Complex<F>, b: Complex<F>, c: Complex<F>, d: Complex<F>) -> Complex<F>
+{
+    let two = F::one() + F::one();
+    let m = (a + d)/two;
+    let r = (((a - d)/two).powi(2) + b*c).sqrt();
+    let (l1, l2) = (m + r, m - r);
+    if (l1 - d).norm() <= (l2 - d).norm() {l1} else {l2}
+}
+
+/// Returns the Givens rotation (c, s), with c real, such that [[c, s], [-s*, c]][x, y]ᵀ = [r, 0]ᵀ.
+pub(crate) fn givens<F: Float>(x: Complex<F>, y: Complex<F>) -> (F, Complex<F>)
+{
+    if y.is_zero()
+    {
+        return (F::one(), Complex::zero())
+    }
+    if x.is_zero()
+    {
+        return (F::zero(), y.conj()/y.norm())
+    }
+    let r = x.norm().hypot(y.norm());
+    (x.norm()/r, x/x.norm()*y.conj()/r)
+}
\ No newline at end of file
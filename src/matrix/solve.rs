@@ -0,0 +1,97 @@
+use num_complex::ComplexFloat;
+
+use crate::{SquareMatrix, LinalgError, lu::lu_in_place, triangular::{forward_substitute, back_substitute}};
+
+pub trait Solve<Rhs>: SquareMatrix
+{
+    type Output;
+
+    /// Returns the solution of the linear system if the matrix is non-singular
+    /// 
+    /// A⁻¹b
+    /// 
+    /// The system is solved by LU-decomposition with partial pivoting, without forming the inverse.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rhs` - A vector or a matrix with height equal this matrix's length
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// // Returns none if matrix is singular
+    /// let a = [
+    ///     [1.0, 2.0],
+    ///     [2.0, 4.0]
+    /// ];
+    /// let b = [1.0, 2.0];
+    /// assert_eq!(a.solve(b), None);
+    /// 
+    /// // Otherwise returns x such that Ax = b
+    /// let a = [
+    ///     [2.0, 0.0],
+    ///     [0.0, 4.0]
+    /// ];
+    /// let b = [1.0, 2.0];
+    /// assert_eq!(a.solve(b), Some([0.5, 0.5]));
+    /// ```
+    fn solve(&self, rhs: Rhs) -> Option<Self::Output>;
+
+    /// Returns the solution of the linear system, or an error if the matrix is singular
+    /// 
+    /// A⁻¹b
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a = [
+    ///     [1.0, 2.0],
+    ///     [2.0, 4.0]
+    /// ];
+    /// let b = [1.0, 2.0];
+    /// assert_eq!(a.try_solve(b), Err(LinalgError::Singular));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_solve(&self, rhs: Rhs) -> Result<Self::Output, LinalgError>
+    {
+        self.solve(rhs).ok_or(LinalgError::Singular)
+    }
+}
+
+impl<F: ComplexFloat, const N: usize> Solve<[F; N]> for [[F; N]; N]
+where
+    Self: SquareMatrix + Solve<[[F; 1]; N], Output = [[F; 1]; N]>
+{
+    type Output = [F; N];
+
+    fn solve(&self, rhs: [F; N]) -> Option<Self::Output>
+    {
+        self.solve(rhs.map(|b| [b])).map(|x| x.map(|[x]| x))
+    }
+}
+
+impl<F: ComplexFloat, const N: usize, const K: usize> Solve<[[F; K]; N]> for [[F; N]; N]
+where
+    Self: SquareMatrix
+{
+    type Output = [[F; K]; N];
+
+    fn solve(&self, rhs: [[F; K]; N]) -> Option<Self::Output>
+    {
+        let mut lu = *self;
+        let perm = lu_in_place(&mut lu);
+        if (0..N).any(|i| lu[i][i].is_zero())
+        {
+            return None
+        }
+
+        let mut x: [[F; K]; N] = array_init::array_init(|r| rhs[perm[r]]);
+        forward_substitute(|r, c| lu[r][c], &mut x, true);
+        back_substitute(|r, c| lu[r][c], &mut x, false);
+        Some(x)
+    }
+}
\ No newline at end of file
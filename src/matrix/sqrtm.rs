@@ -0,0 +1,135 @@
+use num_complex::{Complex, ComplexFloat};
+use num_traits::{Float, FloatConst};
+
+use crate::{SquareMatrix, Schur, LinalgError, funm::{unitary_similarity, on_negative_real_axis, real_part}};
+
+pub trait Sqrtm: SquareMatrix
+{
+    type Output;
+
+    /// Returns the principal square root of the given matrix, if it exists and the Schur-decomposition converges
+    /// 
+    /// √A
+    /// 
+    /// The principal square root is the one whose eigenvalues have positive real part.
+    /// Returns none if the matrix has eigenvalues on the negative real axis, or a repeated zero eigenvalue without a square root.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [4.0, 0.0],
+    ///     [0.0, 9.0]
+    /// ];
+    /// assert_eq!(a.sqrtm(), Some([[2.0, 0.0], [0.0, 3.0]]));
+    /// ```
+    fn sqrtm(&self) -> Option<Self::Output>
+    {
+        self.try_sqrtm().ok()
+    }
+
+    /// Returns the principal square root of the given matrix, or an error telling why it could not be computed
+    /// 
+    /// √A
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a = [
+    ///     [-1.0, 0.0],
+    ///     [0.0, 1.0]
+    /// ];
+    /// assert_eq!(a.try_sqrtm(), Err(LinalgError::EigenvalueOnNegativeRealAxis));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_sqrtm(&self) -> Result<Self::Output, LinalgError>;
+}
+
+impl<F: Float + FloatConst, const N: usize> Sqrtm for [[Complex<F>; N]; N]
+where
+    Self: Schur<OutputQ = Self, OutputT = Self>
+{
+    type Output = Self;
+
+    fn try_sqrtm(&self) -> Result<Self::Output, LinalgError>
+    {
+        let (q, t) = self.try_schur()?;
+        if (0..N).any(|i| on_negative_real_axis(&t, i, false))
+        {
+            return Err(LinalgError::EigenvalueOnNegativeRealAxis)
+        }
+        let u = sqrtm_triangular(&t).ok_or(LinalgError::Singular)?;
+        Ok(unitary_similarity(&q, &u))
+    }
+}
+
+impl<const N: usize> Sqrtm for [[f32; N]; N]
+where
+    Self: SquareMatrix,
+    [[Complex<f32>; N]; N]: Sqrtm<Output = [[Complex<f32>; N]; N]>
+{
+    type Output = Self;
+
+    fn try_sqrtm(&self) -> Result<Self::Output, LinalgError>
+    {
+        self.map(|ar| ar.map(Complex::from))
+            .try_sqrtm()
+            .and_then(|x| real_part(x).ok_or(LinalgError::EigenvalueOnNegativeRealAxis))
+    }
+}
+
+impl<const N: usize> Sqrtm for [[f64; N]; N]
+where
+    Self: SquareMatrix,
+    [[Complex<f64>; N]; N]: Sqrtm<Output = [[Complex<f64>; N]; N]>
+{
+    type Output = Self;
+
+    fn try_sqrtm(&self) -> Result<Self::Output, LinalgError>
+    {
+        self.map(|ar| ar.map(Complex::from))
+            .try_sqrtm()
+            .and_then(|x| real_part(x).ok_or(LinalgError::EigenvalueOnNegativeRealAxis))
+    }
+}
+
+/// Returns the principal square root of an upper-triangular matrix by the Björck-Hammarling recurrence.
+/// 
+/// Returns none if the recurrence divides by zero, which happens for a repeated zero eigenvalue.
+pub(crate) fn sqrtm_triangular<F: ComplexFloat, const N: usize>(t: &[[F; N]; N]) -> Option<[[F; N]; N]>
+{
+    let mut u = [[F::zero(); N]; N];
+    for i in 0..N
+    {
+        u[i][i] = t[i][i].sqrt();
+    }
+    for d in 1..N
+    {
+        for i in 0..N - d
+        {
+            let j = i + d;
+            let s = (i + 1..j)
+                .map(|k| u[i][k]*u[k][j])
+                .fold(F::zero(), |a, b| a + b);
+            let den = u[i][i] + u[j][j];
+            let num = t[i][j] - s;
+            u[i][j] = if den.is_zero()
+            {
+                if !num.is_zero()
+                {
+                    return None
+                }
+                F::zero()
+            }
+            else
+            {
+                num/den
+            };
+        }
+    }
+    Some(u)
+}
\ No newline at end of file
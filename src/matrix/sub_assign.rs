@@ -0,0 +1,49 @@
+use std::ops::SubAssign;
+
+use crate::Matrix;
+
+pub trait MSubAssign<Rhs: Matrix>: Matrix
+{
+    /// Subtracts a matrix of equal dimensions from this one in place
+    /// 
+    /// A ← A - B
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rhs` - The subtrahend matrix
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let mut a = [
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0]
+    /// ];
+    /// let b = [
+    ///     [4.0, 3.0],
+    ///     [2.0, 1.0]
+    /// ];
+    /// let s = a.sub(b);
+    /// a.sub_assign(b);
+    /// assert_eq!(a, s);
+    /// ```
+    fn sub_assign(&mut self, rhs: Rhs);
+}
+
+impl<T1, T2, const H: usize, const L: usize> MSubAssign<[[T2; L]; H]> for [[T1; L]; H]
+where
+    Self: Matrix,
+    [[T2; L]; H]: Matrix,
+    T1: SubAssign<T2>
+{
+    fn sub_assign(&mut self, rhs: [[T2; L]; H])
+    {
+        for (row, rhs_row) in self.iter_mut().zip(rhs)
+        {
+            for (x, rhs_x) in row.iter_mut().zip(rhs_row)
+            {
+                *x -= rhs_x
+            }
+        }
+    }
+}
\ No newline at end of file
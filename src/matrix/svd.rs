@@ -0,0 +1,194 @@
+use num_complex::ComplexFloat;
+use num_traits::{Float, NumCast, Zero};
+
+use crate::{matrix_init, Matrix, Herm, LinalgError, symmetric_eig::jacobi_rotation};
+
+const MAX_SWEEPS: usize = 100;
+
+pub trait Svd: Matrix
+{
+    type OutputU;
+    type OutputS;
+    type OutputVh;
+
+    /// Returns the singular value decomposition of the given matrix if the Jacobi-iteration converges
+    /// 
+    /// A = UΣVᴴ
+    /// 
+    /// U and V are unitary, and Σ is diagonal with the non-negative singular values in descending order.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [3.0, 0.0],
+    ///     [0.0, -4.0],
+    ///     [0.0, 0.0]
+    /// ];
+    /// let (u, s, vh) = a.svd().unwrap();
+    /// assert_eq!(s, [
+    ///     [4.0, 0.0],
+    ///     [0.0, 3.0],
+    ///     [0.0, 0.0]
+    /// ]);
+    /// ```
+    fn svd(&self) -> Option<(Self::OutputU, Self::OutputS, Self::OutputVh)>;
+
+    /// Returns the singular value decomposition of the given matrix, or an error if the Jacobi-iteration does not converge
+    /// 
+    /// A = UΣVᴴ
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a = [
+    ///     [3.0, 0.0],
+    ///     [0.0, -4.0]
+    /// ];
+    /// let (u, s, vh) = a.try_svd()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn try_svd(&self) -> Result<(Self::OutputU, Self::OutputS, Self::OutputVh), LinalgError>
+    {
+        self.svd().ok_or(LinalgError::NoConvergence)
+    }
+}
+
+impl<F: ComplexFloat, const L: usize, const H: usize> Svd for [[F; L]; H]
+where
+    Self: Matrix + Herm<Output = [[F; H]; L]>,
+    [[F; L]; L]: Herm<Output = [[F; L]; L]>,
+    [[F; H]; H]: Herm<Output = [[F; H]; H]>
+{
+    type OutputU = [[F; H]; H];
+    type OutputS = [[F; L]; H];
+    type OutputVh = [[F; L]; L];
+
+    fn svd(&self) -> Option<(Self::OutputU, Self::OutputS, Self::OutputVh)>
+    {
+        if H >= L
+        {
+            let (mut u, mut v) = ([[F::zero(); H]; H], [[F::zero(); L]; L]);
+            let s = svd_tall(self, &mut u, &mut v)?;
+            Some((u, matrix_init(|r, c| if r == c {F::from(s[c]).unwrap()} else {F::zero()}), v.herm()))
+        }
+        else
+        {
+            // Aᴴ = UΣVᴴ gives A = VΣᵀUᴴ
+            let (mut u, mut v) = ([[F::zero(); L]; L], [[F::zero(); H]; H]);
+            let s = svd_tall(&self.herm(), &mut u, &mut v)?;
+            Some((v, matrix_init(|r, c| if r == c {F::from(s[r]).unwrap()} else {F::zero()}), u.herm()))
+        }
+    }
+}
+
+/// Computes the singular value decomposition A = UΣVᴴ of a matrix with H ≥ L by one-sided Jacobi-rotations.
+/// 
+/// Writes U and V, and returns the diagonal of Σ, or none if the iteration did not converge.
+pub(crate) fn svd_tall<F: ComplexFloat, const L: usize, const H: usize>(
+    a: &[[F; L]; H],
+    u: &mut [[F; H]; H],
+    v: &mut [[F; L]; L]
+) -> Option<[F::Real; L]>
+{
+    // Orthogonalize the columns of W = AV
+    let mut w = *a;
+    *v = matrix_init(|r, c| if r == c {F::one()} else {F::zero()});
+    let mut converged = false;
+    for _ in 0..MAX_SWEEPS
+    {
+        converged = true;
+        for p in 0..L
+        {
+            for q in p + 1..L
+            {
+                let (alpha, beta, gamma) = w.iter()
+                    .map(|wr| (wr[p].abs()*wr[p].abs(), wr[q].abs()*wr[q].abs(), wr[p].conj()*wr[q]))
+                    .fold((F::Real::zero(), F::Real::zero(), F::zero()), |a, b| (a.0 + b.0, a.1 + b.1, a.2 + b.2));
+                if gamma.abs() <= F::Real::epsilon()*Float::sqrt(alpha*beta)
+                {
+                    continue
+                }
+                converged = false;
+                let (c, sp, sq) = jacobi_rotation(alpha, beta, gamma);
+                for wr in w.iter_mut().chain(v.iter_mut())
+                {
+                    (wr[p], wr[q]) = (wr[p]*c - wr[q]*sq, wr[p]*sp + wr[q]*c);
+                }
+            }
+        }
+        if converged
+        {
+            break
+        }
+    }
+    if !converged
+    {
+        return None
+    }
+
+    let sigma: [F::Real; L] = array_init::array_init(|c| Float::sqrt(w.iter()
+        .map(|wr| wr[c].abs()*wr[c].abs())
+        .fold(F::Real::zero(), |a, b| a + b)
+    ));
+    let mut order: [usize; L] = array_init::array_init(|i| i);
+    order.sort_by(|&i, &j| sigma[j].partial_cmp(&sigma[i]).unwrap_or(std::cmp::Ordering::Equal));
+    let sigma = order.map(|i| sigma[i]);
+    *v = v.map(|vr| order.map(|i| vr[i]));
+
+    // Normalize the columns belonging to non-zero singular values, and complete the rest of U to an orthonormal basis
+    let tolerance = sigma[0]*F::Real::epsilon()*<F::Real as NumCast>::from(H).unwrap();
+    let mut rank = 0;
+    while rank < L && sigma[rank] > tolerance
+    {
+        for (ur, wr) in u.iter_mut().zip(w.iter())
+        {
+            ur[rank] = wr[order[rank]]/F::from(sigma[rank]).unwrap();
+        }
+        rank += 1;
+    }
+    complete_orthonormal(u, rank);
+    Some(sigma)
+}
+
+/// Replaces the columns of `u` from index `rank` and out with an orthonormal basis of the complement of the first `rank` orthonormal columns.
+pub(crate) fn complete_orthonormal<F: ComplexFloat, const H: usize>(u: &mut [[F; H]; H], rank: usize)
+{
+    for k in rank..H
+    {
+        // Project each unit vector onto the complement twice, and keep the one that retains the most
+        let mut best = ([F::zero(); H], F::Real::zero());
+        for i in 0..H
+        {
+            let mut x = [F::zero(); H];
+            x[i] = F::one();
+            for _ in 0..2
+            {
+                for j in 0..k
+                {
+                    let d = (0..H)
+                        .map(|r| u[r][j].conj()*x[r])
+                        .fold(F::zero(), |a, b| a + b);
+                    for (xr, ur) in x.iter_mut().zip(u.iter())
+                    {
+                        *xr = *xr - ur[j]*d;
+                    }
+                }
+            }
+            let x_abs = crate::hessenberg::norm(&x);
+            if x_abs > best.1
+            {
+                best = (x, x_abs);
+            }
+        }
+        for (ur, xr) in u.iter_mut().zip(best.0)
+        {
+            ur[k] = xr/F::from(best.1).unwrap();
+        }
+    }
+}
\ No newline at end of file
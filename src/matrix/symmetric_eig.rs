@@ -0,0 +1,142 @@
+use num_complex::ComplexFloat;
+use num_traits::{Float, Zero, One};
+
+use crate::{SquareMatrix, LinalgError};
+
+const MAX_SWEEPS: usize = 100;
+
+pub trait SymmetricEig: SquareMatrix
+{
+    type OutputValues;
+    type OutputVectors;
+
+    /// Returns the real eigenvalues in ascending order together with orthonormal eigenvectors of a symmetric or Hermitian matrix,
+    /// if the Jacobi-iteration converges
+    /// 
+    /// A = VΛVᴴ
+    /// 
+    /// Only the lower triangle of the matrix is read.
+    /// Each column of V belongs to the eigenvalue at the same index.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let a = [
+    ///     [2.0, 1.0],
+    ///     [1.0, 2.0]
+    /// ];
+    /// let (lambda, v) = a.symmetric_eig().unwrap();
+    /// assert_eq!(lambda, [1.0, 3.0]);
+    /// ```
+    fn symmetric_eig(&self) -> Option<(Self::OutputValues, Self::OutputVectors)>;
+
+    /// Returns the eigenvalues and eigenvectors of a Hermitian matrix, or an error if the Jacobi-iteration does not converge
+    /// 
+    /// A = VΛVᴴ
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let a = [
+    ///     [2.0, 1.0],
+    ///     [1.0, 2.0]
+    /// ];
+    /// let (lambda, v) = a.try_symmetric_eig()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_symmetric_eig(&self) -> Result<(Self::OutputValues, Self::OutputVectors), LinalgError>
+    {
+        self.symmetric_eig().ok_or(LinalgError::NoConvergence)
+    }
+}
+
+impl<F: ComplexFloat, const N: usize> SymmetricEig for [[F; N]; N]
+where
+    Self: SquareMatrix
+{
+    type OutputValues = [F::Real; N];
+    type OutputVectors = Self;
+
+    fn symmetric_eig(&self) -> Option<(Self::OutputValues, Self::OutputVectors)>
+    {
+        let mut a: Self = array_init::array_init(|r| array_init::array_init(|c| match r.cmp(&c)
+        {
+            std::cmp::Ordering::Less => self[c][r].conj(),
+            std::cmp::Ordering::Equal => F::from(self[r][r].re()).unwrap(),
+            std::cmp::Ordering::Greater => self[r][c]
+        }));
+        let mut v = Self::identity();
+
+        let norm = a.iter()
+            .flatten()
+            .map(|an| an.abs()*an.abs())
+            .fold(F::Real::zero(), |a, b| a + b);
+        let mut converged = false;
+        for _ in 0..MAX_SWEEPS
+        {
+            let off = (0..N)
+                .flat_map(|r| (0..r).map(move |c| (r, c)))
+                .map(|(r, c)| a[r][c].abs()*a[r][c].abs())
+                .fold(F::Real::zero(), |a, b| a + b);
+            if off <= F::Real::epsilon()*F::Real::epsilon()*norm
+            {
+                converged = true;
+                break
+            }
+            for p in 0..N
+            {
+                for q in p + 1..N
+                {
+                    if a[p][q].is_zero()
+                    {
+                        continue
+                    }
+                    let (c, sp, sq) = jacobi_rotation(a[p][p].re(), a[q][q].re(), a[p][q]);
+                    for ar in a.iter_mut().chain(v.iter_mut())
+                    {
+                        (ar[p], ar[q]) = (ar[p]*c - ar[q]*sq, ar[p]*sp + ar[q]*c);
+                    }
+                    let [ap, aq] = a.get_disjoint_mut([p, q]).unwrap();
+                    for (apc, aqc) in ap.iter_mut().zip(aq.iter_mut())
+                    {
+                        (*apc, *aqc) = (*apc*c - *aqc*sp, *apc*sq + *aqc*c);
+                    }
+                    a[p][q] = F::zero();
+                    a[q][p] = F::zero();
+                    a[p][p] = F::from(a[p][p].re()).unwrap();
+                    a[q][q] = F::from(a[q][q].re()).unwrap();
+                }
+            }
+        }
+        if !converged
+        {
+            return None
+        }
+
+        let mut order: [usize; N] = array_init::array_init(|i| i);
+        order.sort_by(|&i, &j| a[i][i].re().partial_cmp(&a[j][j].re()).unwrap_or(std::cmp::Ordering::Equal));
+        let lambda = order.map(|i| a[i][i].re());
+        let v = array_init::array_init(|r| order.map(|i| v[r][i]));
+        Some((lambda, v))
+    }
+}
+
+/// Returns the Jacobi rotation J = [[c, s·e^{iφ}], [-s·e^{-iφ}, c]] that zeroes the off-diagonal elements of JᴴAJ,
+/// where A = [[app, apq], [apq*, aqq]] is Hermitian and e^{iφ} is the phase of apq.
+/// 
+/// Returns (c, s·e^{iφ}, s·e^{-iφ}).
+pub(crate) fn jacobi_rotation<F: ComplexFloat>(app: F::Real, aqq: F::Real, apq: F) -> (F, F, F)
+{
+    let apq_abs = apq.abs();
+    let phase = apq/F::from(apq_abs).unwrap();
+    let two = F::Real::one() + F::Real::one();
+    let theta = (aqq - app)/(two*apq_abs);
+    let t = Float::signum(theta)/(Float::abs(theta) + Float::sqrt(theta*theta + F::Real::one()));
+    let c = F::Real::one()/Float::sqrt(t*t + F::Real::one());
+    let s = F::from(t*c).unwrap();
+    (F::from(c).unwrap(), phase*s, phase.conj()*s)
+}
@@ -40,14 +40,4 @@ where
     {
         matrix_init(|r, c| self[c][r].clone())
     }
-}
-
-/*impl<F: Float, const L: usize> Transpose for [F; L]
-{
-    type Output = [[F; 1]; L];
-
-    fn transpose(&self) -> Self::Output
-    {
-        matrix_init(|r, c| self[r])
-    }
-}*/
\ No newline at end of file
+}
\ No newline at end of file
@@ -0,0 +1,38 @@
+use crate::SquareMatrix;
+
+pub trait TransposeInPlace: SquareMatrix
+{
+    /// Transposes the given square matrix in place by swapping elements across the diagonal
+    /// 
+    /// A ← Aᵀ
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let mut a = [
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0]
+    /// ];
+    /// let at = a.transpose();
+    /// a.transpose_in_place();
+    /// assert_eq!(a, at);
+    /// ```
+    fn transpose_in_place(&mut self);
+}
+
+impl<F, const N: usize> TransposeInPlace for [[F; N]; N]
+where
+    Self: SquareMatrix
+{
+    fn transpose_in_place(&mut self)
+    {
+        for c in 1..N
+        {
+            let (upper, lower) = self.split_at_mut(c);
+            for (r, row) in upper.iter_mut().enumerate()
+            {
+                std::mem::swap(&mut row[c], &mut lower[0][r])
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,494 @@
+use num_complex::ComplexFloat;
+
+use crate::{SquareMatrix, LinalgError};
+
+pub trait TriangularSolve<Rhs>: SquareMatrix
+{
+    type Output;
+
+    /// Returns the solution of an upper-triangular linear system by back-substitution, if the diagonal has no zeros
+    /// 
+    /// U⁻¹b
+    /// 
+    /// Only the upper triangle of the matrix is read.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rhs` - A vector or a matrix with height equal this matrix's length
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let u = [
+    ///     [2.0, 1.0],
+    ///     [0.0, 4.0]
+    /// ];
+    /// let b = [4.0, 8.0];
+    /// assert_eq!(u.solve_upper_triangular(b), Some([1.0, 2.0]));
+    /// ```
+    fn solve_upper_triangular(&self, rhs: Rhs) -> Option<Self::Output>;
+
+    /// Returns the solution of an upper-triangular linear system, or an error if the diagonal has a zero
+    /// 
+    /// U⁻¹b
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let u = [
+    ///     [2.0, 1.0],
+    ///     [0.0, 0.0]
+    /// ];
+    /// assert_eq!(u.try_solve_upper_triangular([4.0, 8.0]), Err(LinalgError::Singular));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_solve_upper_triangular(&self, rhs: Rhs) -> Result<Self::Output, LinalgError>
+    {
+        self.solve_upper_triangular(rhs).ok_or(LinalgError::Singular)
+    }
+
+    /// Returns the solution of a lower-triangular linear system by forward-substitution, if the diagonal has no zeros
+    /// 
+    /// L⁻¹b
+    /// 
+    /// Only the lower triangle of the matrix is read.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rhs` - A vector or a matrix with height equal this matrix's length
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let l = [
+    ///     [2.0, 0.0],
+    ///     [1.0, 4.0]
+    /// ];
+    /// let b = [2.0, 9.0];
+    /// assert_eq!(l.solve_lower_triangular(b), Some([1.0, 2.0]));
+    /// ```
+    fn solve_lower_triangular(&self, rhs: Rhs) -> Option<Self::Output>;
+
+    /// Returns the solution of a lower-triangular linear system, or an error if the diagonal has a zero
+    /// 
+    /// L⁻¹b
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let l = [
+    ///     [0.0, 0.0],
+    ///     [1.0, 4.0]
+    /// ];
+    /// assert_eq!(l.try_solve_lower_triangular([4.0, 8.0]), Err(LinalgError::Singular));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_solve_lower_triangular(&self, rhs: Rhs) -> Result<Self::Output, LinalgError>
+    {
+        self.solve_lower_triangular(rhs).ok_or(LinalgError::Singular)
+    }
+
+    /// Returns the solution of an upper-triangular linear system with ones on the diagonal
+    /// 
+    /// U⁻¹b
+    /// 
+    /// Only the strictly upper triangle of the matrix is read.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rhs` - A vector or a matrix with height equal this matrix's length
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let u = [
+    ///     [1.0, 2.0],
+    ///     [0.0, 1.0]
+    /// ];
+    /// let b = [5.0, 2.0];
+    /// assert_eq!(u.solve_unit_upper_triangular(b), [1.0, 2.0]);
+    /// ```
+    fn solve_unit_upper_triangular(&self, rhs: Rhs) -> Self::Output;
+
+    /// Returns the solution of a lower-triangular linear system with ones on the diagonal
+    /// 
+    /// L⁻¹b
+    /// 
+    /// Only the strictly lower triangle of the matrix is read.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rhs` - A vector or a matrix with height equal this matrix's length
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let l = [
+    ///     [1.0, 0.0],
+    ///     [3.0, 1.0]
+    /// ];
+    /// let b = [1.0, 5.0];
+    /// assert_eq!(l.solve_unit_lower_triangular(b), [1.0, 2.0]);
+    /// ```
+    fn solve_unit_lower_triangular(&self, rhs: Rhs) -> Self::Output;
+
+    /// Returns the solution of the transposed upper-triangular linear system, if the diagonal has no zeros
+    /// 
+    /// (Uᵀ)⁻¹b
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rhs` - A vector or a matrix with height equal this matrix's length
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let u = [
+    ///     [2.0, 1.0],
+    ///     [0.0, 4.0]
+    /// ];
+    /// let b = [2.0, 9.0];
+    /// assert_eq!(u.solve_upper_triangular_transpose(b), Some([1.0, 2.0]));
+    /// ```
+    fn solve_upper_triangular_transpose(&self, rhs: Rhs) -> Option<Self::Output>;
+
+    /// Returns the solution of the transposed upper-triangular linear system, or an error if the diagonal has a zero
+    /// 
+    /// (Uᵀ)⁻¹b
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let u = [
+    ///     [2.0, 1.0],
+    ///     [0.0, 0.0]
+    /// ];
+    /// assert_eq!(u.try_solve_upper_triangular_transpose([4.0, 8.0]), Err(LinalgError::Singular));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_solve_upper_triangular_transpose(&self, rhs: Rhs) -> Result<Self::Output, LinalgError>
+    {
+        self.solve_upper_triangular_transpose(rhs).ok_or(LinalgError::Singular)
+    }
+
+    /// Returns the solution of the transposed lower-triangular linear system, if the diagonal has no zeros
+    /// 
+    /// (Lᵀ)⁻¹b
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rhs` - A vector or a matrix with height equal this matrix's length
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let l = [
+    ///     [2.0, 0.0],
+    ///     [1.0, 4.0]
+    /// ];
+    /// let b = [4.0, 8.0];
+    /// assert_eq!(l.solve_lower_triangular_transpose(b), Some([1.0, 2.0]));
+    /// ```
+    fn solve_lower_triangular_transpose(&self, rhs: Rhs) -> Option<Self::Output>;
+
+    /// Returns the solution of the transposed lower-triangular linear system, or an error if the diagonal has a zero
+    /// 
+    /// (Lᵀ)⁻¹b
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let l = [
+    ///     [0.0, 0.0],
+    ///     [1.0, 4.0]
+    /// ];
+    /// assert_eq!(l.try_solve_lower_triangular_transpose([4.0, 8.0]), Err(LinalgError::Singular));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_solve_lower_triangular_transpose(&self, rhs: Rhs) -> Result<Self::Output, LinalgError>
+    {
+        self.solve_lower_triangular_transpose(rhs).ok_or(LinalgError::Singular)
+    }
+
+    /// Returns the solution of the conjugate-transposed upper-triangular linear system, if the diagonal has no zeros
+    /// 
+    /// (Uᴴ)⁻¹b
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rhs` - A vector or a matrix with height equal this matrix's length
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let u = [
+    ///     [Complex::new(0.0, 2.0), Complex::new(1.0, 0.0)],
+    ///     [Complex::zero(), Complex::new(4.0, 0.0)]
+    /// ];
+    /// let b = [Complex::new(0.0, -2.0), Complex::new(9.0, 0.0)];
+    /// assert_eq!(u.solve_upper_triangular_herm(b), Some([Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)]));
+    /// ```
+    fn solve_upper_triangular_herm(&self, rhs: Rhs) -> Option<Self::Output>;
+
+    /// Returns the solution of the conjugate-transposed upper-triangular linear system, or an error if the diagonal has a zero
+    /// 
+    /// (Uᴴ)⁻¹b
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # use num_complex::Complex;
+    /// # use num_traits::Zero;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let u = [
+    ///     [Complex::new(0.0, 2.0), Complex::new(1.0, 0.0)],
+    ///     [Complex::zero(), Complex::zero()]
+    /// ];
+    /// assert_eq!(u.try_solve_upper_triangular_herm([Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)]), Err(LinalgError::Singular));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_solve_upper_triangular_herm(&self, rhs: Rhs) -> Result<Self::Output, LinalgError>
+    {
+        self.solve_upper_triangular_herm(rhs).ok_or(LinalgError::Singular)
+    }
+
+    /// Returns the solution of the conjugate-transposed lower-triangular linear system, if the diagonal has no zeros
+    /// 
+    /// (Lᴴ)⁻¹b
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rhs` - A vector or a matrix with height equal this matrix's length
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// let l = [
+    ///     [Complex::new(2.0, 0.0), Complex::zero()],
+    ///     [Complex::new(0.0, 1.0), Complex::new(4.0, 0.0)]
+    /// ];
+    /// let b = [Complex::new(2.0, -2.0), Complex::new(8.0, 0.0)];
+    /// assert_eq!(l.solve_lower_triangular_herm(b), Some([Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)]));
+    /// ```
+    fn solve_lower_triangular_herm(&self, rhs: Rhs) -> Option<Self::Output>;
+
+    /// Returns the solution of the conjugate-transposed lower-triangular linear system, or an error if the diagonal has a zero
+    /// 
+    /// (Lᴴ)⁻¹b
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # #![feature(generic_const_exprs)]
+    /// # use array_matrix::*;
+    /// # use num_complex::Complex;
+    /// # use num_traits::Zero;
+    /// # fn main() -> Result<(), LinalgError> {
+    /// let l = [
+    ///     [Complex::zero(), Complex::zero()],
+    ///     [Complex::new(0.0, 1.0), Complex::new(4.0, 0.0)]
+    /// ];
+    /// assert_eq!(l.try_solve_lower_triangular_herm([Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)]), Err(LinalgError::Singular));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_solve_lower_triangular_herm(&self, rhs: Rhs) -> Result<Self::Output, LinalgError>
+    {
+        self.solve_lower_triangular_herm(rhs).ok_or(LinalgError::Singular)
+    }
+}
+
+impl<F: ComplexFloat, const N: usize> TriangularSolve<[F; N]> for [[F; N]; N]
+where
+    Self: TriangularSolve<[[F; 1]; N], Output = [[F; 1]; N]>
+{
+    type Output = [F; N];
+
+    fn solve_upper_triangular(&self, rhs: [F; N]) -> Option<Self::Output>
+    {
+        self.solve_upper_triangular(rhs.map(|b| [b])).map(|x| x.map(|[x]| x))
+    }
+    fn solve_lower_triangular(&self, rhs: [F; N]) -> Option<Self::Output>
+    {
+        self.solve_lower_triangular(rhs.map(|b| [b])).map(|x| x.map(|[x]| x))
+    }
+    fn solve_unit_upper_triangular(&self, rhs: [F; N]) -> Self::Output
+    {
+        self.solve_unit_upper_triangular(rhs.map(|b| [b])).map(|[x]| x)
+    }
+    fn solve_unit_lower_triangular(&self, rhs: [F; N]) -> Self::Output
+    {
+        self.solve_unit_lower_triangular(rhs.map(|b| [b])).map(|[x]| x)
+    }
+    fn solve_upper_triangular_transpose(&self, rhs: [F; N]) -> Option<Self::Output>
+    {
+        self.solve_upper_triangular_transpose(rhs.map(|b| [b])).map(|x| x.map(|[x]| x))
+    }
+    fn solve_lower_triangular_transpose(&self, rhs: [F; N]) -> Option<Self::Output>
+    {
+        self.solve_lower_triangular_transpose(rhs.map(|b| [b])).map(|x| x.map(|[x]| x))
+    }
+    fn solve_upper_triangular_herm(&self, rhs: [F; N]) -> Option<Self::Output>
+    {
+        self.solve_upper_triangular_herm(rhs.map(|b| [b])).map(|x| x.map(|[x]| x))
+    }
+    fn solve_lower_triangular_herm(&self, rhs: [F; N]) -> Option<Self::Output>
+    {
+        self.solve_lower_triangular_herm(rhs.map(|b| [b])).map(|x| x.map(|[x]| x))
+    }
+}
+
+impl<F: ComplexFloat, const N: usize, const K: usize> TriangularSolve<[[F; K]; N]> for [[F; N]; N]
+where
+    Self: SquareMatrix
+{
+    type Output = [[F; K]; N];
+
+    fn solve_upper_triangular(&self, rhs: [[F; K]; N]) -> Option<Self::Output>
+    {
+        if !diagonal_nonzero(self)
+        {
+            return None
+        }
+        let mut x = rhs;
+        back_substitute(|r, c| self[r][c], &mut x, false);
+        Some(x)
+    }
+    fn solve_lower_triangular(&self, rhs: [[F; K]; N]) -> Option<Self::Output>
+    {
+        if !diagonal_nonzero(self)
+        {
+            return None
+        }
+        let mut x = rhs;
+        forward_substitute(|r, c| self[r][c], &mut x, false);
+        Some(x)
+    }
+    fn solve_unit_upper_triangular(&self, rhs: [[F; K]; N]) -> Self::Output
+    {
+        let mut x = rhs;
+        back_substitute(|r, c| self[r][c], &mut x, true);
+        x
+    }
+    fn solve_unit_lower_triangular(&self, rhs: [[F; K]; N]) -> Self::Output
+    {
+        let mut x = rhs;
+        forward_substitute(|r, c| self[r][c], &mut x, true);
+        x
+    }
+    fn solve_upper_triangular_transpose(&self, rhs: [[F; K]; N]) -> Option<Self::Output>
+    {
+        if !diagonal_nonzero(self)
+        {
+            return None
+        }
+        let mut x = rhs;
+        forward_substitute(|r, c| self[c][r], &mut x, false);
+        Some(x)
+    }
+    fn solve_lower_triangular_transpose(&self, rhs: [[F; K]; N]) -> Option<Self::Output>
+    {
+        if !diagonal_nonzero(self)
+        {
+            return None
+        }
+        let mut x = rhs;
+        back_substitute(|r, c| self[c][r], &mut x, false);
+        Some(x)
+    }
+    fn solve_upper_triangular_herm(&self, rhs: [[F; K]; N]) -> Option<Self::Output>
+    {
+        if !diagonal_nonzero(self)
+        {
+            return None
+        }
+        let mut x = rhs;
+        forward_substitute(|r, c| self[c][r].conj(), &mut x, false);
+        Some(x)
+    }
+    fn solve_lower_triangular_herm(&self, rhs: [[F; K]; N]) -> Option<Self::Output>
+    {
+        if !diagonal_nonzero(self)
+        {
+            return None
+        }
+        let mut x = rhs;
+        back_substitute(|r, c| self[c][r].conj(), &mut x, false);
+        Some(x)
+    }
+}
+
+/// Returns whether the triangular matrix has no zero on its diagonal, so that its systems have unique solutions.
+fn diagonal_nonzero<F: ComplexFloat, const N: usize>(a: &[[F; N]; N]) -> bool
+{
+    (0..N).all(|i| !a[i][i].is_zero())
+}
+
+/// Solves Lx = b in place by forward-substitution, where `l(r, c)` returns the element of L in the lower triangle.
+pub(crate) fn forward_substitute<F: ComplexFloat, const N: usize, const K: usize>(l: impl Fn(usize, usize) -> F, x: &mut [[F; K]; N], unit: bool)
+{
+    for r in 0..N
+    {
+        for i in 0..r
+        {
+            let xi = x[i];
+            let lri = l(r, i);
+            for (xrc, xic) in x[r].iter_mut().zip(xi)
+            {
+                *xrc = *xrc - lri*xic;
+            }
+        }
+        if !unit
+        {
+            let lrr = l(r, r);
+            for xrc in x[r].iter_mut()
+            {
+                *xrc = *xrc/lrr;
+            }
+        }
+    }
+}
+
+/// Solves Ux = b in place by back-substitution, where `u(r, c)` returns the element of U in the upper triangle.
+pub(crate) fn back_substitute<F: ComplexFloat, const N: usize, const K: usize>(u: impl Fn(usize, usize) -> F, x: &mut [[F; K]; N], unit: bool)
+{
+    for r in (0..N).rev()
+    {
+        for i in r + 1..N
+        {
+            let xi = x[i];
+            let uri = u(r, i);
+            for (xrc, xic) in x[r].iter_mut().zip(xi)
+            {
+                *xrc = *xrc - uri*xic;
+            }
+        }
+        if !unit
+        {
+            let urr = u(r, r);
+            for xrc in x[r].iter_mut()
+            {
+                *xrc = *xrc/urr;
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -7,6 +7,8 @@ pub mod outer;
 pub mod add;
 pub mod sub;
 pub mod conj;
+pub mod column;
+pub mod row;
 
 use num_traits::Zero;
 
@@ -19,6 +21,8 @@ pub use self::outer::*;
 pub use self::add::*;
 pub use self::sub::*;
 pub use self::conj::*;
+pub use self::column::*;
+pub use self::row::*;
 
 pub trait Vector
 {
@@ -0,0 +1,114 @@
+use std::ops::{Add, Deref, DerefMut, Mul};
+
+use crate::Row;
+
+/// A column vector, which behaves as an `N`×1 matrix in products
+/// 
+/// It has the same layout as the array `[F; N]`, and dereferences to it, so all the vector traits are still available.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// # #![feature(generic_const_exprs)]
+/// # use array_matrix::*;
+/// let u = Column([1.0, 2.0]);
+/// let v = Row([3.0, 4.0]);
+/// assert_eq!(u*v, [
+///     [3.0, 4.0],
+///     [6.0, 8.0]
+/// ]);
+/// assert_eq!(u.into_matrix(), [[1.0], [2.0]]);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Column<F, const N: usize>(pub [F; N]);
+
+impl<F, const N: usize> Column<F, N>
+{
+    /// Returns the column vector as an `N`×1 matrix
+    pub fn into_matrix(self) -> [[F; 1]; N]
+    {
+        self.0.map(|un| [un])
+    }
+
+    /// Returns the transposed vector, which is a row vector
+    pub fn transpose(self) -> Row<F, N>
+    {
+        Row(self.0)
+    }
+}
+
+impl<F, const N: usize> From<[F; N]> for Column<F, N>
+{
+    fn from(u: [F; N]) -> Self
+    {
+        Self(u)
+    }
+}
+
+impl<F, const N: usize> From<Column<F, N>> for [F; N]
+{
+    fn from(u: Column<F, N>) -> Self
+    {
+        u.0
+    }
+}
+
+impl<F, const N: usize> From<[[F; 1]; N]> for Column<F, N>
+{
+    fn from(a: [[F; 1]; N]) -> Self
+    {
+        Self(a.map(|[an]| an))
+    }
+}
+
+impl<F, const N: usize> From<Column<F, N>> for [[F; 1]; N]
+{
+    fn from(u: Column<F, N>) -> Self
+    {
+        u.into_matrix()
+    }
+}
+
+impl<F, const N: usize> Deref for Column<F, N>
+{
+    type Target = [F; N];
+
+    fn deref(&self) -> &Self::Target
+    {
+        &self.0
+    }
+}
+
+impl<F, const N: usize> DerefMut for Column<F, N>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target
+    {
+        &mut self.0
+    }
+}
+
+impl<F, const L: usize, const H: usize> Mul<Row<F, L>> for Column<F, H>
+where
+    F: Mul<F> + Clone
+{
+    type Output = [[<F as Mul<F>>::Output; L]; H];
+
+    fn mul(self, rhs: Row<F, L>) -> Self::Output
+    {
+        self.0.map(|ur| array_init::array_init(|c| ur.clone()*rhs[c].clone()))
+    }
+}
+
+impl<F, const N: usize> Add<Column<F, N>> for Column<F, N>
+where
+    F: Add<F>
+{
+    type Output = Column<<F as Add<F>>::Output, N>;
+
+    fn add(self, rhs: Column<F, N>) -> Self::Output
+    {
+        let mut rhs = rhs.0.into_iter();
+        Column(self.0.map(|un| un + rhs.next().unwrap()))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,140 @@
+use std::ops::{Add, Deref, DerefMut, Mul};
+
+use crate::{Column, Mat};
+
+/// A row vector, which behaves as a 1×`N` matrix in products
+/// 
+/// It has the same layout as the array `[F; N]`, and dereferences to it, so all the vector traits are still available.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// # #![feature(generic_const_exprs)]
+/// # use array_matrix::*;
+/// let u = Row([1.0, 2.0]);
+/// let a = [
+///     [1.0, 2.0, 3.0],
+///     [4.0, 5.0, 6.0]
+/// ];
+/// assert_eq!(u*a, Row([9.0, 12.0, 15.0]));
+/// assert_eq!(u*Column([3.0, 4.0]), 11.0);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Row<F, const N: usize>(pub [F; N]);
+
+impl<F, const N: usize> Row<F, N>
+{
+    /// Returns the row vector as a 1×`N` matrix
+    pub fn into_matrix(self) -> [[F; N]; 1]
+    {
+        [self.0]
+    }
+
+    /// Returns the transposed vector, which is a column vector
+    pub fn transpose(self) -> Column<F, N>
+    {
+        Column(self.0)
+    }
+}
+
+impl<F, const N: usize> From<[F; N]> for Row<F, N>
+{
+    fn from(u: [F; N]) -> Self
+    {
+        Self(u)
+    }
+}
+
+impl<F, const N: usize> From<Row<F, N>> for [F; N]
+{
+    fn from(u: Row<F, N>) -> Self
+    {
+        u.0
+    }
+}
+
+impl<F, const N: usize> From<[[F; N]; 1]> for Row<F, N>
+{
+    fn from([a]: [[F; N]; 1]) -> Self
+    {
+        Self(a)
+    }
+}
+
+impl<F, const N: usize> From<Row<F, N>> for [[F; N]; 1]
+{
+    fn from(u: Row<F, N>) -> Self
+    {
+        u.into_matrix()
+    }
+}
+
+impl<F, const N: usize> Deref for Row<F, N>
+{
+    type Target = [F; N];
+
+    fn deref(&self) -> &Self::Target
+    {
+        &self.0
+    }
+}
+
+impl<F, const N: usize> DerefMut for Row<F, N>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target
+    {
+        &mut self.0
+    }
+}
+
+impl<F, const N: usize> Mul<Column<F, N>> for Row<F, N>
+where
+    F: Mul<F> + Clone,
+    <F as Mul<F>>::Output: Add<<F as Mul<F>>::Output, Output = <F as Mul<F>>::Output>
+{
+    type Output = <F as Mul<F>>::Output;
+
+    fn mul(self, rhs: Column<F, N>) -> Self::Output
+    {
+        (0..N).map(|i| self[i].clone()*rhs[i].clone()).reduce(|a, b| a + b).unwrap()
+    }
+}
+
+impl<F, const L: usize, const H: usize> Mul<[[F; L]; H]> for Row<F, H>
+where
+    F: Mul<F> + Clone,
+    <F as Mul<F>>::Output: Add<<F as Mul<F>>::Output, Output = <F as Mul<F>>::Output>
+{
+    type Output = Row<<F as Mul<F>>::Output, L>;
+
+    fn mul(self, rhs: [[F; L]; H]) -> Self::Output
+    {
+        Row(array_init::array_init(|c| (0..H).map(|i| self[i].clone()*rhs[i][c].clone()).reduce(|a, b| a + b).unwrap()))
+    }
+}
+
+impl<F, const L: usize, const H: usize> Mul<Mat<F, H, L>> for Row<F, H>
+where
+    Self: Mul<[[F; L]; H]>
+{
+    type Output = <Self as Mul<[[F; L]; H]>>::Output;
+
+    fn mul(self, rhs: Mat<F, H, L>) -> Self::Output
+    {
+        self*rhs.0
+    }
+}
+
+impl<F, const N: usize> Add<Row<F, N>> for Row<F, N>
+where
+    F: Add<F>
+{
+    type Output = Row<<F as Add<F>>::Output, N>;
+
+    fn add(self, rhs: Row<F, N>) -> Self::Output
+    {
+        let mut rhs = rhs.0.into_iter();
+        Row(self.0.map(|un| un + rhs.next().unwrap()))
+    }
+}
\ No newline at end of file